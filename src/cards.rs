@@ -0,0 +1,186 @@
+//! Cards shown in the responsive grid, and where they come from.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Locale;
+
+/// A single card in the grid.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Card {
+    pub id: u64,
+    pub title: String,
+    pub body: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Creation time in seconds since the Unix epoch.
+    #[serde(default)]
+    pub created: u64,
+    /// Pinned cards are shown before all others.
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+impl Card {
+    pub fn new(id: u64, title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            body: body.into(),
+            tags: Vec::new(),
+            created: 0,
+            pinned: false,
+        }
+    }
+
+    pub fn with_tags<S: Into<String>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_created(mut self, created: u64) -> Self {
+        self.created = created;
+        self
+    }
+
+    /// The first id that more than one of `cards` uses, if any.
+    ///
+    /// Ids key the cards' widgets and the per-card actions, so a card set must not
+    /// repeat them.
+    pub fn duplicate_id(cards: &[Card]) -> Option<u64> {
+        let mut seen = HashSet::new();
+        cards
+            .iter()
+            .map(|card| card.id)
+            .find(|&id| !seen.insert(id))
+    }
+
+    /// The six cards a fresh app starts with.
+    pub fn demo_set() -> Vec<Card> {
+        // 2024-01-01T00:00:00Z, one card per day
+        const FIRST_CREATED: u64 = 1_704_067_200;
+        let tags: [&[&str]; 6] = [
+            &["Layout"],
+            &["Testing"],
+            &["Layout", "Accessibility"],
+            &["Testing"],
+            &["Accessibility"],
+            &["Layout", "Testing"],
+        ];
+        (1..=6)
+            .zip(tags)
+            .map(|(n, tags)| {
+                Card::new(
+                    n,
+                    format!("Card {n}"),
+                    "This card wraps text and scales with the layout width.",
+                )
+                .with_tags(tags.iter().copied())
+                .with_created(FIRST_CREATED + (n - 1) * 86_400)
+            })
+            .collect()
+    }
+}
+
+/// What a card's "Action" button runs.
+pub trait CardCommand {
+    /// Run the command for `card` and return a status message to show, in `locale`.
+    fn run(&mut self, card: &Card, locale: Locale) -> String;
+}
+
+impl<F: FnMut(&Card, Locale) -> String> CardCommand for F {
+    fn run(&mut self, card: &Card, locale: Locale) -> String {
+        self(card, locale)
+    }
+}
+
+/// Default [`CardCommand`]: reports which card was acted on.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AnnounceCard;
+
+impl CardCommand for AnnounceCard {
+    fn run(&mut self, card: &Card, locale: Locale) -> String {
+        locale.format("card.action_ran", &[("title", &card.title)])
+    }
+}
+
+/// Somewhere cards can be loaded from.
+pub trait CardSource {
+    fn load(&mut self) -> Result<Vec<Card>, CardSourceError>;
+}
+
+/// Cards held in memory.
+#[derive(Clone, Debug, Default)]
+pub struct StaticCards(pub Vec<Card>);
+
+impl CardSource for StaticCards {
+    fn load(&mut self) -> Result<Vec<Card>, CardSourceError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// A RON file containing a list of cards.
+#[derive(Clone, Debug)]
+pub struct FileCardSource {
+    pub path: PathBuf,
+}
+
+impl FileCardSource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Write `cards` in the format [`FileCardSource`] reads.
+    pub fn write(path: &Path, cards: &[Card]) -> Result<(), CardSourceError> {
+        let text = ron::ser::to_string_pretty(cards, ron::ser::PrettyConfig::default())
+            .map_err(|err| CardSourceError::Format(err.to_string()))?;
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+impl CardSource for FileCardSource {
+    fn load(&mut self) -> Result<Vec<Card>, CardSourceError> {
+        let text = std::fs::read_to_string(&self.path)?;
+        ron::from_str(&text).map_err(|err| CardSourceError::Format(err.to_string()))
+    }
+}
+
+/// Why a [`CardSource`] could not produce cards.
+#[derive(Debug)]
+pub enum CardSourceError {
+    Io(std::io::Error),
+    Format(String),
+    /// Two cards share this id.
+    DuplicateId(u64),
+}
+
+impl CardSourceError {
+    /// The error's message in `locale`; [`fmt::Display`] gives the English one.
+    pub fn message_in(&self, locale: Locale) -> String {
+        match self {
+            CardSourceError::Io(err) => locale.io_error(err),
+            CardSourceError::Format(err) => locale.format("error.invalid_cards", &[("error", err)]),
+            CardSourceError::DuplicateId(id) => {
+                locale.format("error.duplicate_card_id", &[("id", id)])
+            }
+        }
+    }
+}
+
+impl fmt::Display for CardSourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message_in(Locale::En))
+    }
+}
+
+impl std::error::Error for CardSourceError {}
+
+impl From<std::io::Error> for CardSourceError {
+    fn from(err: std::io::Error) -> Self {
+        CardSourceError::Io(err)
+    }
+}
@@ -0,0 +1,199 @@
+//! Reusable dialogs.
+
+use eframe::egui;
+
+use crate::i18n::Locale;
+
+/// Answer to [`SaveChangesDialog`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SaveChoice {
+    Save,
+    Discard,
+    Cancel,
+}
+
+/// "Save changes?" prompt shown before unsaved work would be thrown away.
+pub struct SaveChangesDialog<'a> {
+    document_name: &'a str,
+    save_enabled: bool,
+    locale: Locale,
+}
+
+impl<'a> SaveChangesDialog<'a> {
+    pub fn new(document_name: &'a str) -> Self {
+        Self {
+            document_name,
+            save_enabled: true,
+            locale: Locale::default(),
+        }
+    }
+
+    /// Disable the Save button, e.g. while the document can't be saved.
+    pub fn save_enabled(mut self, enabled: bool) -> Self {
+        self.save_enabled = enabled;
+        self
+    }
+
+    /// Language of the dialog's text.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Show the dialog as a modal; returns the user's choice on the frame it is made.
+    ///
+    /// The backdrop blocks input to everything behind it, and a click on it leaves the
+    /// question open. Escape counts as [`SaveChoice::Cancel`].
+    pub fn show(self, ctx: &egui::Context) -> Option<SaveChoice> {
+        let locale = self.locale;
+        let id = egui::Id::new("save_changes_dialog");
+        // The modal layer is only registered once shown, so this is true on the opening frame
+        let opening = ctx.memory(|mem| mem.top_modal_layer().is_none_or(|layer| layer.id != id));
+        let mut choice = None;
+        let response = egui::Modal::new(id).show(ctx, |ui| {
+            ui.heading(locale.text("dialog.save_title"));
+            ui.label(locale.format("dialog.save_message", &[("name", &self.document_name)]));
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        self.save_enabled,
+                        egui::Button::new(locale.text("dialog.save")),
+                    )
+                    .clicked()
+                {
+                    choice = Some(SaveChoice::Save);
+                }
+                if ui.button(locale.text("dialog.discard")).clicked() {
+                    choice = Some(SaveChoice::Discard);
+                }
+                let cancel = ui.button(locale.text("dialog.cancel"));
+                if opening {
+                    // Move focus off the widget that opened the dialog, onto the safe answer
+                    cancel.request_focus();
+                }
+                if cancel.clicked() {
+                    choice = Some(SaveChoice::Cancel);
+                }
+            });
+        });
+        if choice.is_none()
+            && response.is_top_modal
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape))
+        {
+            choice = Some(SaveChoice::Cancel);
+        }
+        choice
+    }
+}
+
+/// How a [`ConfirmationDialog`] was closed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfirmOutcome {
+    /// The confirm button or Enter.
+    Confirmed,
+    /// The cancel button or Escape.
+    Cancelled,
+    /// Closed without answering, by clicking outside the dialog.
+    Dismissed,
+}
+
+impl ConfirmOutcome {
+    /// Message key of the outcome's short label, as shown in `Last choice: …`.
+    pub fn key(self) -> &'static str {
+        match self {
+            ConfirmOutcome::Confirmed => "common.yes",
+            ConfirmOutcome::Cancelled => "common.no",
+            ConfirmOutcome::Dismissed => "confirm.dismissed",
+        }
+    }
+
+    /// English text for [`ConfirmOutcome::key`].
+    pub fn label(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+}
+
+/// Yes/No question in a modal, reporting a [`ConfirmOutcome`].
+pub struct ConfirmationDialog<'a> {
+    title: &'a str,
+    message: &'a str,
+    confirm_text: &'a str,
+    cancel_text: &'a str,
+    on_outcome: Option<Box<dyn FnOnce(ConfirmOutcome) + 'a>>,
+}
+
+impl<'a> ConfirmationDialog<'a> {
+    pub fn new(title: &'a str, message: &'a str) -> Self {
+        Self {
+            title,
+            message,
+            confirm_text: "Yes",
+            cancel_text: "No",
+            on_outcome: None,
+        }
+    }
+
+    pub fn confirm_text(mut self, text: &'a str) -> Self {
+        self.confirm_text = text;
+        self
+    }
+
+    pub fn cancel_text(mut self, text: &'a str) -> Self {
+        self.cancel_text = text;
+        self
+    }
+
+    /// Called with the outcome on the frame the dialog closes.
+    pub fn on_outcome(mut self, callback: impl FnOnce(ConfirmOutcome) + 'a) -> Self {
+        self.on_outcome = Some(Box::new(callback));
+        self
+    }
+
+    /// Show the dialog as a modal; returns the outcome on the frame it is decided.
+    ///
+    /// The backdrop blocks input to everything behind it, and keyboard focus stays
+    /// inside the dialog. Enter counts as [`ConfirmOutcome::Confirmed`], Escape as
+    /// [`ConfirmOutcome::Cancelled`] and a click on the backdrop as
+    /// [`ConfirmOutcome::Dismissed`].
+    pub fn show(self, ctx: &egui::Context) -> Option<ConfirmOutcome> {
+        let id = egui::Id::new("confirmation_dialog").with(self.title);
+        // The modal layer is only registered once shown, so this is true on the opening frame
+        let opening = ctx.memory(|mem| mem.top_modal_layer().is_none_or(|layer| layer.id != id));
+        let mut outcome = None;
+        let response = egui::Modal::new(id).show(ctx, |ui| {
+            ui.heading(self.title);
+            ui.label(self.message);
+            ui.horizontal(|ui| {
+                let confirm = ui.button(self.confirm_text);
+                if opening {
+                    // Move focus off the widget that opened the dialog
+                    confirm.request_focus();
+                }
+                if confirm.clicked() {
+                    outcome = Some(ConfirmOutcome::Confirmed);
+                }
+                if ui.button(self.cancel_text).clicked() {
+                    outcome = Some(ConfirmOutcome::Cancelled);
+                }
+            });
+        });
+        if outcome.is_none() && response.backdrop_response.clicked() {
+            outcome = Some(ConfirmOutcome::Dismissed);
+        }
+        if outcome.is_none() && response.is_top_modal && !response.any_popup_open {
+            outcome = ctx.input_mut(|i| {
+                if i.consume_key(egui::Modifiers::NONE, egui::Key::Escape) {
+                    Some(ConfirmOutcome::Cancelled)
+                } else if i.consume_key(egui::Modifiers::NONE, egui::Key::Enter) {
+                    Some(ConfirmOutcome::Confirmed)
+                } else {
+                    None
+                }
+            });
+        }
+        if let (Some(outcome), Some(callback)) = (outcome, self.on_outcome) {
+            callback(outcome);
+        }
+        outcome
+    }
+}
@@ -0,0 +1,170 @@
+//! The document edited through the File menu.
+//!
+//! A document holds the form fields, the counter and the card set, and is stored as
+//! human-readable RON. Paths come from a [`FilePicker`], so tests can swap the native
+//! flow for a temp-dir or in-memory one.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::DemoApp;
+use crate::cards::Card;
+use crate::i18n::Locale;
+use crate::validation::AGE_RANGE;
+
+/// Current on-disk version of [`Document`].
+pub const DOCUMENT_VERSION: u32 = 1;
+
+/// Everything File → Save writes to disk.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Document {
+    pub version: u32,
+    pub name: String,
+    pub age: u32,
+    pub counter: i32,
+    pub cards: Vec<Card>,
+}
+
+impl Default for Document {
+    /// The state File → New resets to.
+    fn default() -> Self {
+        Self {
+            version: DOCUMENT_VERSION,
+            name: String::new(),
+            age: 0,
+            counter: 0,
+            cards: Card::demo_set(),
+        }
+    }
+}
+
+impl Document {
+    pub fn from_app(app: &DemoApp) -> Self {
+        Self {
+            version: DOCUMENT_VERSION,
+            name: app.name.clone(),
+            age: app.age,
+            counter: app.counter,
+            cards: app.cards().to_vec(),
+        }
+    }
+
+    pub fn apply_to(self, app: &mut DemoApp) {
+        app.name = self.name;
+        app.age = self.age.min(*AGE_RANGE.end());
+        app.counter = self.counter;
+        app.set_cards(self.cards);
+    }
+
+    pub fn to_ron(&self) -> Result<String, DocumentError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| DocumentError::Format(err.to_string()))
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, DocumentError> {
+        let document: Self =
+            ron::from_str(text).map_err(|err| DocumentError::Format(err.to_string()))?;
+        if document.version > DOCUMENT_VERSION {
+            return Err(DocumentError::UnsupportedVersion(document.version));
+        }
+        if let Some(id) = Card::duplicate_id(&document.cards) {
+            return Err(DocumentError::DuplicateCardId(id));
+        }
+        Ok(Self {
+            version: DOCUMENT_VERSION,
+            ..document
+        })
+    }
+
+    pub fn read(path: &Path) -> Result<Self, DocumentError> {
+        Self::from_ron(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), DocumentError> {
+        std::fs::write(path, self.to_ron()?)?;
+        Ok(())
+    }
+}
+
+/// Why a document could not be opened or saved.
+#[derive(Debug)]
+pub enum DocumentError {
+    Io(std::io::Error),
+    Format(String),
+    UnsupportedVersion(u32),
+    /// Two cards share this id.
+    DuplicateCardId(u64),
+}
+
+impl DocumentError {
+    /// The error's message in `locale`; [`fmt::Display`] gives the English one.
+    pub fn message_in(&self, locale: Locale) -> String {
+        match self {
+            DocumentError::Io(err) => locale.io_error(err),
+            DocumentError::Format(err) => {
+                locale.format("error.invalid_document", &[("error", err)])
+            }
+            DocumentError::UnsupportedVersion(version) => {
+                locale.format("error.unsupported_version", &[("version", version)])
+            }
+            DocumentError::DuplicateCardId(id) => {
+                locale.format("error.duplicate_card_id", &[("id", id)])
+            }
+        }
+    }
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message_in(Locale::En))
+    }
+}
+
+impl std::error::Error for DocumentError {}
+
+impl From<std::io::Error> for DocumentError {
+    fn from(err: std::io::Error) -> Self {
+        DocumentError::Io(err)
+    }
+}
+
+/// Chooses where documents are opened from and saved to.
+///
+/// Returning `None` means the user cancelled.
+pub trait FilePicker {
+    fn pick_open(&mut self) -> Option<PathBuf>;
+    fn pick_save(&mut self, current: Option<&Path>) -> Option<PathBuf>;
+}
+
+/// Always opens and saves the same file.
+///
+/// This is the default picker; it avoids pulling a native dialog crate into the demo.
+#[derive(Clone, Debug)]
+pub struct FixedPathPicker {
+    pub path: PathBuf,
+}
+
+impl FixedPathPicker {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Default for FixedPathPicker {
+    fn default() -> Self {
+        Self::new("demo_document.ron")
+    }
+}
+
+impl FilePicker for FixedPathPicker {
+    fn pick_open(&mut self) -> Option<PathBuf> {
+        Some(self.path.clone())
+    }
+
+    fn pick_save(&mut self, current: Option<&Path>) -> Option<PathBuf> {
+        Some(current.map_or_else(|| self.path.clone(), Path::to_path_buf))
+    }
+}
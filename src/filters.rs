@@ -0,0 +1,167 @@
+//! Filter, search and sort state for the card grid.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+
+use crate::cards::Card;
+use crate::i18n::Locale;
+
+/// Order in which matching cards are shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Document order.
+    #[default]
+    Manual,
+    TitleAscending,
+    TitleDescending,
+    NewestFirst,
+    OldestFirst,
+}
+
+impl SortOrder {
+    pub const ALL: [SortOrder; 5] = [
+        SortOrder::Manual,
+        SortOrder::TitleAscending,
+        SortOrder::TitleDescending,
+        SortOrder::NewestFirst,
+        SortOrder::OldestFirst,
+    ];
+
+    /// Message key of the order's name in the Sort combo box.
+    pub fn key(self) -> &'static str {
+        match self {
+            SortOrder::Manual => "sort.manual",
+            SortOrder::TitleAscending => "sort.title_ascending",
+            SortOrder::TitleDescending => "sort.title_descending",
+            SortOrder::NewestFirst => "sort.newest_first",
+            SortOrder::OldestFirst => "sort.oldest_first",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+
+    fn compare(self, a: &Card, b: &Card) -> Ordering {
+        let by_title = || a.title.to_lowercase().cmp(&b.title.to_lowercase());
+        match self {
+            SortOrder::Manual => Ordering::Equal,
+            SortOrder::TitleAscending => by_title(),
+            SortOrder::TitleDescending => by_title().reverse(),
+            SortOrder::NewestFirst => b.created.cmp(&a.created),
+            SortOrder::OldestFirst => a.created.cmp(&b.created),
+        }
+    }
+}
+
+/// What the Filters panel narrows the grid down to.
+///
+/// Lives on [`crate::DemoApp`], so it is shared by the stacked and side+central layouts.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilterState {
+    /// A card must carry every selected tag.
+    pub tags: BTreeSet<String>,
+    /// Case-insensitive substring matched against title and body.
+    pub search: String,
+    pub sort: SortOrder,
+}
+
+impl FilterState {
+    pub fn matches(&self, card: &Card) -> bool {
+        let has_tags = self.tags.iter().all(|tag| card.tags.contains(tag));
+        let needle = self.search.trim().to_lowercase();
+        let has_text = needle.is_empty()
+            || card.title.to_lowercase().contains(&needle)
+            || card.body.to_lowercase().contains(&needle);
+        has_tags && has_text
+    }
+
+    /// Matching cards in display order: pinned cards first, then by the sort order.
+    /// Sorting is stable, so ties keep document order.
+    pub fn apply<'a>(&self, cards: &'a [Card]) -> Vec<&'a Card> {
+        self.apply_indices(cards)
+            .into_iter()
+            .map(|index| &cards[index])
+            .collect()
+    }
+
+    /// [`FilterState::apply`] as indices into `cards`.
+    pub fn apply_indices(&self, cards: &[Card]) -> Vec<usize> {
+        let mut shown: Vec<usize> = (0..cards.len())
+            .filter(|&index| self.matches(&cards[index]))
+            .collect();
+        shown.sort_by(|&a, &b| {
+            let (a, b) = (&cards[a], &cards[b]);
+            b.pinned
+                .cmp(&a.pinned)
+                .then_with(|| self.sort.compare(a, b))
+        });
+        shown
+    }
+
+    pub fn toggle_tag(&mut self, tag: &str) {
+        if !self.tags.remove(tag) {
+            self.tags.insert(tag.to_owned());
+        }
+    }
+}
+
+/// Every tag used by `cards`, sorted.
+pub fn all_tags(cards: &[Card]) -> BTreeSet<String> {
+    cards
+        .iter()
+        .flat_map(|card| card.tags.iter().cloned())
+        .collect()
+}
+
+/// Titles that more than one of `cards` has.
+pub fn shared_titles(cards: &[Card]) -> HashSet<String> {
+    let mut seen = HashSet::new();
+    cards
+        .iter()
+        .filter(|card| !seen.insert(&card.title))
+        .map(|card| card.title.clone())
+        .collect()
+}
+
+/// The filtered card order and tag list, kept between frames.
+///
+/// Both are recomputed only when the card set's revision or the filters change, so an
+/// idle frame does no work proportional to the number of cards.
+#[derive(Clone, Debug, Default)]
+pub struct FilteredCards {
+    /// Card revision and filters the lists were computed for.
+    source: Option<(u64, FilterState)>,
+    /// Indices of the matching cards, in display order.
+    pub indices: Vec<usize>,
+    /// Every tag used by the cards, sorted.
+    pub tags: BTreeSet<String>,
+    /// Titles used by more than one card; their controls' labels add the card id.
+    pub shared_titles: HashSet<String>,
+    /// How many times the cards were filtered and sorted.
+    pub passes: usize,
+}
+
+impl FilteredCards {
+    /// Bring the lists up to date with `cards` at `revision` and `filters`.
+    pub fn update(&mut self, revision: u64, cards: &[Card], filters: &FilterState) {
+        let cards_changed = self
+            .source
+            .as_ref()
+            .is_none_or(|(source_revision, _)| *source_revision != revision);
+        let filters_changed = self
+            .source
+            .as_ref()
+            .is_none_or(|(_, source_filters)| source_filters != filters);
+        if !cards_changed && !filters_changed {
+            return;
+        }
+        if cards_changed {
+            self.tags = all_tags(cards);
+            self.shared_titles = shared_titles(cards);
+        }
+        self.indices = filters.apply_indices(cards);
+        self.passes += 1;
+        self.source = Some((revision, filters.clone()));
+    }
+}
@@ -0,0 +1,270 @@
+//! Virtualized card grid: only rows that intersect the visible area are laid out.
+//!
+//! Rows outside the viewport are replaced by empty space sized from measured (or
+//! estimated) row heights, so the enclosing `ScrollArea` keeps its full extent.
+//! Off-screen cards within [`PLACEHOLDER_ROWS`] rows of the viewport still get a
+//! lightweight AccessKit node carrying their title, which answers `ScrollIntoView` by
+//! scrolling the row into the viewport. Any other card is reached with
+//! [`CardGrid::scroll_to_card`], so the per-frame AccessKit work doesn't grow with the
+//! card count.
+
+use std::ops::Range;
+
+use eframe::egui;
+use egui::accesskit;
+
+use crate::cards::Card;
+
+/// Height assumed for rows that have not been laid out yet, in points.
+const ESTIMATED_ROW_HEIGHT: f32 = 120.0;
+/// Extra rows laid out above and below the viewport, so keyboard focus can move
+/// into the next row and scrolling never reveals a gap.
+const OVERSCAN_ROWS: usize = 1;
+/// Vertical gap after each row, in points.
+const ROW_SPACING: f32 = 4.0;
+/// Rows above and below the laid-out ones whose cards get an AccessKit placeholder.
+pub const PLACEHOLDER_ROWS: usize = 25;
+
+/// What the grid laid out on the last frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GridStats {
+    pub rows: usize,
+    /// Rows that were laid out; everything else was skipped.
+    pub rendered_rows: Range<usize>,
+    pub rendered_cards: usize,
+    /// Off-screen cards exposed to AccessKit as placeholders.
+    pub placeholders: usize,
+}
+
+/// Row-major card grid that remembers row heights between frames.
+#[derive(Clone, Debug, Default)]
+pub struct CardGrid {
+    /// Column count and width the measured heights belong to.
+    shape: Option<(usize, u32)>,
+    heights: Vec<Option<f32>>,
+    /// Top of each row plus the total height, from `heights` and the estimate for
+    /// unmeasured rows. Rebuilt only when a height changes.
+    offsets: Vec<f32>,
+    /// Card still being scrolled to; see [`CardGrid::scroll_to_card`].
+    scroll_target: Option<u64>,
+    pub stats: GridStats,
+}
+
+impl CardGrid {
+    /// Scroll the card with `id` into view, however far it is from the viewport.
+    ///
+    /// Its row may not have been measured yet, so the grid keeps scrolling to the
+    /// card's current position over the next frames until it is laid out on screen.
+    /// Ignored if the card isn't shown.
+    pub fn scroll_to_card(&mut self, id: u64) {
+        self.scroll_target = Some(id);
+    }
+
+    /// Lay out `cards[shown[..]]` in `columns` columns, calling `add_card` only for
+    /// visible rows.
+    ///
+    /// Columns run right to left when `ui`'s layout prefers it, as it does for
+    /// `Layout::top_down(Align::Max)`.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        cards: &[Card],
+        shown: &[usize],
+        columns: usize,
+        mut add_card: impl FnMut(&mut egui::Ui, &Card),
+    ) {
+        let columns = columns.max(1);
+        let right_to_left = ui.layout().prefer_right_to_left();
+        let rows = shown.len().div_ceil(columns);
+        let width = ui.available_width();
+        // Heights measured at another width or column count no longer apply
+        let shape = (columns, width.to_bits());
+        if self.shape != Some(shape) {
+            self.shape = Some(shape);
+            self.heights.clear();
+            self.offsets.clear();
+        }
+        self.heights.resize(rows, None);
+        if self.offsets.len() != rows + 1 {
+            self.rebuild_offsets();
+        }
+
+        let offsets = &self.offsets;
+        let row_cards = |row: usize| {
+            shown[row * columns..]
+                .iter()
+                .take(columns)
+                .map(|&index| &cards[index])
+        };
+        let top = ui.cursor().top();
+        let left = ui.cursor().left();
+        let clip = ui.clip_rect();
+        let visible = visible_rows(
+            offsets,
+            egui::Rangef::new(clip.top() - top, clip.bottom() - top),
+        );
+        let rendered =
+            visible.start.saturating_sub(OVERSCAN_ROWS)..(visible.end + OVERSCAN_ROWS).min(rows);
+
+        let spacing = ui.spacing().item_spacing.x;
+        let column_width = (width - spacing * (columns - 1) as f32) / columns as f32;
+        let cell = |row: usize, column: usize| {
+            let column = if right_to_left {
+                columns - 1 - column
+            } else {
+                column
+            };
+            let min = egui::pos2(
+                left + column as f32 * (column_width + spacing),
+                top + offsets[row],
+            );
+            egui::Rect::from_min_size(
+                min,
+                egui::vec2(column_width, offsets[row + 1] - offsets[row]),
+            )
+        };
+        if let Some(target) = self.scroll_target {
+            match shown.iter().position(|&index| cards[index].id == target) {
+                Some(position) => {
+                    let (row, column) = (position / columns, position % columns);
+                    let rect = cell(row, column);
+                    if visible.contains(&row) && clip.y_range().contains(rect.center().y) {
+                        self.scroll_target = None;
+                    } else {
+                        ui.scroll_to_rect(rect, Some(egui::Align::Center));
+                        ui.ctx().request_repaint();
+                    }
+                }
+                None => self.scroll_target = None,
+            }
+        }
+        let placeholder_rows = (rendered.start.saturating_sub(PLACEHOLDER_ROWS)..rendered.start)
+            .chain(rendered.end..(rendered.end + PLACEHOLDER_ROWS).min(rows));
+        let mut placeholders = 0;
+        'placeholders: for row in placeholder_rows {
+            for (column, card) in row_cards(row).enumerate() {
+                if !placeholder(ui, card, cell(row, column)) {
+                    // AccessKit is off, so no placeholder is needed for any card
+                    break 'placeholders;
+                }
+                placeholders += 1;
+            }
+        }
+
+        let (space_above, space_below) = (
+            offsets[rendered.start],
+            offsets[rows] - offsets[rendered.end],
+        );
+        ui.add_space(space_above);
+        // Every row takes exactly one auto id (its scope), and skipped rows are counted
+        // as if laid out, so a card's widget ids, and with them keyboard focus, don't
+        // change as the window of laid-out rows moves
+        ui.skip_ahead_auto_ids(rendered.start);
+        let rows_top = ui.cursor().top();
+        let mut measured = false;
+        for row in rendered.clone() {
+            let row_top = ui.cursor().top();
+            ui.scope(|ui| {
+                ui.columns(columns, |uis| {
+                    if right_to_left {
+                        uis.reverse();
+                    }
+                    for (col_ui, card) in uis.iter_mut().zip(row_cards(row)) {
+                        add_card(col_ui, card);
+                    }
+                });
+            });
+            ui.add_space(ROW_SPACING);
+            let height = Some(ui.cursor().top() - row_top);
+            if self.heights[row] != height {
+                self.heights[row] = height;
+                measured = true;
+            }
+        }
+        scroll_to_new_focus(ui, egui::Rangef::new(rows_top, ui.cursor().top()));
+        ui.skip_ahead_auto_ids(rows - rendered.end);
+        ui.add_space(space_below);
+        if measured {
+            self.rebuild_offsets();
+        }
+
+        self.stats = GridStats {
+            rows,
+            rendered_cards: shown
+                .len()
+                .min(rendered.end * columns)
+                .saturating_sub(rendered.start * columns),
+            rendered_rows: rendered,
+            placeholders,
+        };
+    }
+
+    fn rebuild_offsets(&mut self) {
+        let estimate = self.estimated_height();
+        self.offsets.clear();
+        self.offsets.push(0.0);
+        for height in &self.heights {
+            let last = self.offsets[self.offsets.len() - 1];
+            self.offsets.push(last + height.unwrap_or(estimate));
+        }
+    }
+
+    /// Average of the measured rows, or a fixed guess before anything was measured.
+    fn estimated_height(&self) -> f32 {
+        let (sum, count) = self
+            .heights
+            .iter()
+            .flatten()
+            .fold((0.0, 0), |(sum, count), height| (sum + height, count + 1));
+        if count == 0 {
+            ESTIMATED_ROW_HEIGHT
+        } else {
+            sum / count as f32
+        }
+    }
+}
+
+/// Rows whose span in `offsets` overlaps `viewport`, both relative to the grid top.
+fn visible_rows(offsets: &[f32], viewport: egui::Rangef) -> Range<usize> {
+    let rows = offsets.len() - 1;
+    let start = offsets[1..].partition_point(|&bottom| bottom <= viewport.min);
+    let end = offsets[..rows].partition_point(|&top| top < viewport.max);
+    start.min(end)..end
+}
+
+/// Tab can land on a control in an overscan row that is still off-screen; scroll it
+/// into view so the row after it is laid out in turn and Tab can keep going.
+fn scroll_to_new_focus(ui: &egui::Ui, rows: egui::Rangef) {
+    let Some(focused) = ui
+        .memory(|memory| memory.focused())
+        .and_then(|id| ui.ctx().read_response(id))
+    else {
+        return;
+    };
+    if focused.gained_focus()
+        && rows.contains(focused.rect.center().y)
+        && !ui.clip_rect().contains_rect(focused.rect)
+    {
+        ui.scroll_to_rect(focused.rect, None);
+    }
+}
+
+/// Expose an off-screen card to AccessKit. Returns `false` when AccessKit is disabled.
+fn placeholder(ui: &egui::Ui, card: &Card, rect: egui::Rect) -> bool {
+    let id = ui.id().with(("card_placeholder", card.id));
+    let built = ui.ctx().accesskit_node_builder(id, |node| {
+        node.set_role(accesskit::Role::Group);
+        node.set_label(card.title.clone());
+        node.set_bounds(accesskit::Rect {
+            x0: rect.min.x.into(),
+            y0: rect.min.y.into(),
+            x1: rect.max.x.into(),
+            y1: rect.max.y.into(),
+        });
+        node.add_action(accesskit::Action::ScrollIntoView);
+    });
+    if ui.input(|i| i.has_accesskit_action_request(id, accesskit::Action::ScrollIntoView)) {
+        ui.scroll_to_rect(rect, Some(egui::Align::Center));
+    }
+    built.is_some()
+}
@@ -0,0 +1,229 @@
+//! Undo/redo for edits made through the UI.
+//!
+//! Every user-visible mutation of [`DemoApp`] is an [`Edit`] that knows its value
+//! before and after, or for the card list the single card it touches, so it can be
+//! reverted and re-applied. [`History`] keeps the
+//! undo and redo stacks, merges consecutive edits of the same text or number field
+//! into one step, and drops the oldest steps past its depth limit.
+
+use std::collections::VecDeque;
+
+use crate::cards::Card;
+use crate::theme::Theme;
+use crate::{DemoApp, ScalingMode};
+
+/// Default number of undo steps kept.
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// One reversible change to [`DemoApp`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Edit {
+    Counter {
+        before: i32,
+        after: i32,
+    },
+    Name {
+        before: String,
+        after: String,
+    },
+    Age {
+        before: u32,
+        after: u32,
+    },
+    ScalingMode {
+        before: ScalingMode,
+        after: ScalingMode,
+    },
+    Theme {
+        before: Theme,
+        after: Theme,
+    },
+    /// `card` was inserted into the card list at `index`.
+    Insert {
+        index: usize,
+        card: Card,
+    },
+    /// `card` was removed from the card list at `index`.
+    Remove {
+        index: usize,
+        card: Card,
+    },
+    /// The pin of the card with `id` was flipped.
+    TogglePin {
+        id: u64,
+    },
+}
+
+impl Edit {
+    pub fn apply(&self, app: &mut DemoApp) {
+        self.set(app, false);
+    }
+
+    pub fn revert(&self, app: &mut DemoApp) {
+        self.set(app, true);
+    }
+
+    fn set(&self, app: &mut DemoApp, undo: bool) {
+        match self {
+            Edit::Counter { before, after } => app.counter = if undo { *before } else { *after },
+            Edit::Name { before, after } => {
+                app.name = if undo { before } else { after }.clone();
+            }
+            Edit::Age { before, after } => app.age = if undo { *before } else { *after },
+            Edit::ScalingMode { before, after } => {
+                app.scaling_mode = if undo { *before } else { *after }
+            }
+            Edit::Theme { before, after } => app.theme = if undo { *before } else { *after },
+            Edit::Insert { index, card } | Edit::Remove { index, card } => {
+                // Undoing an insert removes the card, undoing a removal puts it back
+                let inserting = matches!(self, Edit::Insert { .. }) != undo;
+                let cards = app.cards_mut();
+                if inserting {
+                    cards.insert(*index, card.clone());
+                } else {
+                    cards.remove(*index);
+                }
+            }
+            Edit::TogglePin { id } => {
+                if let Some(card) = app.cards_mut().iter_mut().find(|card| card.id == *id) {
+                    card.pinned = !card.pinned;
+                }
+            }
+        }
+    }
+
+    /// Fold `next` into this edit if both change the same field.
+    fn merge(&mut self, next: &Edit) -> bool {
+        match (self, next) {
+            (Edit::Name { after, .. }, Edit::Name { after: next, .. }) => {
+                after.clone_from(next);
+                true
+            }
+            (Edit::Age { after, .. }, Edit::Age { after: next, .. }) => {
+                *after = *next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_noop(&self) -> bool {
+        match self {
+            Edit::Counter { before, after } => before == after,
+            Edit::Name { before, after } => before == after,
+            Edit::Age { before, after } => before == after,
+            Edit::ScalingMode { before, after } => before == after,
+            Edit::Theme { before, after } => before == after,
+            Edit::Insert { .. } | Edit::Remove { .. } | Edit::TogglePin { .. } => false,
+        }
+    }
+}
+
+/// Undo and redo stacks with coalescing and a depth limit.
+#[derive(Clone, Debug)]
+pub struct History {
+    undo: VecDeque<Edit>,
+    redo: Vec<Edit>,
+    limit: usize,
+    /// Whether the next field edit may merge into the last step.
+    coalescing: bool,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::with_limit(DEFAULT_HISTORY_LIMIT)
+    }
+}
+
+impl History {
+    /// Keep at most `limit` undo steps.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit,
+            coalescing: false,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        self.trim();
+    }
+
+    /// Record an edit that has already been applied.
+    ///
+    /// Consecutive Name (or Age) edits merge into one step until [`History::seal`].
+    pub fn push(&mut self, edit: Edit) {
+        if edit.is_noop() {
+            return;
+        }
+        self.redo.clear();
+        if self.coalescing
+            && let Some(last) = self.undo.back_mut()
+            && last.merge(&edit)
+        {
+            if last.is_noop() {
+                self.undo.pop_back();
+                self.coalescing = false;
+            }
+            return;
+        }
+        self.coalescing = matches!(edit, Edit::Name { .. } | Edit::Age { .. });
+        self.undo.push_back(edit);
+        self.trim();
+    }
+
+    /// End the current merge group, e.g. when the edited field loses focus.
+    pub fn seal(&mut self) {
+        self.coalescing = false;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Forget every step, e.g. after loading a different document.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.coalescing = false;
+    }
+
+    fn trim(&mut self) {
+        while self.undo.len() > self.limit {
+            self.undo.pop_front();
+        }
+    }
+
+    pub(crate) fn take_undo(&mut self) -> Option<Edit> {
+        self.coalescing = false;
+        let edit = self.undo.pop_back()?;
+        self.redo.push(edit.clone());
+        Some(edit)
+    }
+
+    pub(crate) fn take_redo(&mut self) -> Option<Edit> {
+        self.coalescing = false;
+        let edit = self.redo.pop()?;
+        self.undo.push_back(edit.clone());
+        self.trim();
+        Some(edit)
+    }
+}
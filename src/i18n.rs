@@ -0,0 +1,537 @@
+//! Message catalogs for the UI text.
+//!
+//! Every user-facing string is looked up by key in the current [`Locale`]'s catalog,
+//! falling back to English. Templates name their arguments, as in `"Counter: {count}"`.
+//! Plural messages have one entry per CLDR plural category, e.g. `greeting.one` and
+//! `greeting.other`, and are picked with [`Locale::format_plural`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+/// Language of the UI text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+}
+
+/// CLDR plural category of a count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Suffix of the catalog key holding this form.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::De];
+
+    /// The language's name in that language, as listed in View → Language.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::De => "Deutsch",
+        }
+    }
+
+    pub fn plural_category(self, count: u64) -> PluralCategory {
+        match (self, count) {
+            (Locale::En | Locale::De, 1) => PluralCategory::One,
+            (Locale::En | Locale::De, _) => PluralCategory::Other,
+        }
+    }
+
+    /// The message for `key`, in English if this locale lacks it, or the key itself
+    /// if no catalog has it.
+    pub fn text(self, key: &str) -> &str {
+        self.lookup(key)
+            .or_else(|| Locale::En.lookup(key))
+            .unwrap_or(key)
+    }
+
+    /// The message for `key` with its `{name}` placeholders filled from `args`.
+    pub fn format(self, key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+        fill(self.text(key), args)
+    }
+
+    /// The plural form of `key` for `count`, filled from `args` plus `{count}`.
+    pub fn format_plural(
+        self,
+        key: &str,
+        count: u64,
+        args: &[(&str, &dyn fmt::Display)],
+    ) -> String {
+        let template = self
+            .plural_form(key, count)
+            .or_else(|| Locale::En.plural_form(key, count))
+            .unwrap_or(key);
+        let mut args = args.to_vec();
+        args.push(("count", &count));
+        fill(template, &args)
+    }
+
+    /// An I/O error's message. Common kinds are translated; others keep the
+    /// operating system's text.
+    pub fn io_error(self, err: &std::io::Error) -> String {
+        match err.kind() {
+            std::io::ErrorKind::NotFound => self.text("error.io.not_found").to_owned(),
+            std::io::ErrorKind::PermissionDenied => {
+                self.text("error.io.permission_denied").to_owned()
+            }
+            _ => self.format("error.io", &[("error", err)]),
+        }
+    }
+
+    /// Keys of the English catalog that this locale does not translate.
+    pub fn missing_keys(self) -> Vec<&'static str> {
+        let mut missing: Vec<_> = catalog(Locale::En)
+            .keys()
+            .copied()
+            .filter(|key| !catalog(self).contains_key(key))
+            .collect();
+        missing.sort_unstable();
+        missing
+    }
+
+    fn lookup(self, key: &str) -> Option<&'static str> {
+        catalog(self).get(key).copied()
+    }
+
+    fn plural_form(self, key: &str, count: u64) -> Option<&'static str> {
+        let category = self.plural_category(count).as_str();
+        self.lookup(&format!("{key}.{category}"))
+            .or_else(|| self.lookup(&format!("{key}.other")))
+    }
+}
+
+/// Replace each `{name}` in `template` with the matching argument. Unknown
+/// placeholders are left as they are.
+fn fill(template: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        let name = &after[..end];
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => out.push_str(&value.to_string()),
+            None => out.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn catalog(locale: Locale) -> &'static HashMap<&'static str, &'static str> {
+    static EN_CATALOG: LazyLock<HashMap<&str, &str>> =
+        LazyLock::new(|| EN.iter().copied().collect());
+    static DE_CATALOG: LazyLock<HashMap<&str, &str>> =
+        LazyLock::new(|| DE.iter().copied().collect());
+    match locale {
+        Locale::En => &EN_CATALOG,
+        Locale::De => &DE_CATALOG,
+    }
+}
+
+const EN: &[(&str, &str)] = &[
+    ("app.title", "egui_kittest Demo App"),
+    ("app.window_title", "{document} — egui_kittest Demo"),
+    ("document.untitled", "Untitled"),
+    ("error.open", "Could not open {path}: {error}"),
+    (
+        "error.load_cards",
+        "Could not load cards from {path}: {error}",
+    ),
+    ("error.save", "Could not save {path}: {error}"),
+    ("error.form_invalid", "Fix the form errors before saving"),
+    ("error.io", "{error}"),
+    ("error.io.not_found", "file not found"),
+    ("error.io.permission_denied", "permission denied"),
+    ("error.invalid_document", "invalid document: {error}"),
+    (
+        "error.unsupported_version",
+        "document version {version} is newer than supported",
+    ),
+    ("error.duplicate_card_id", "duplicate card id {id}"),
+    ("error.invalid_cards", "invalid card data: {error}"),
+    ("error.theme_file", "{path}: {error}"),
+    ("error.invalid_theme", "invalid theme file: {error}"),
+    (
+        "error.theme_stroke_width",
+        "invalid theme file: stroke_width must be zero or more, found {value}",
+    ),
+    (
+        "error.theme_font_size",
+        "invalid theme file: font_sizes.{field} must be positive, found {size}",
+    ),
+    // Menus
+    ("menu.file", "File"),
+    ("menu.new", "New"),
+    ("menu.open", "Open"),
+    ("menu.save", "Save"),
+    ("menu.edit", "Edit"),
+    ("menu.undo", "Undo"),
+    ("menu.redo", "Redo"),
+    ("menu.view", "View"),
+    ("menu.view_hint", "Layout is responsive to window width"),
+    ("menu.scaling_strategy", "Scaling strategy"),
+    ("menu.scaling_zoom", "Zoom-based"),
+    ("menu.scaling_style", "Style-based"),
+    ("menu.scaling_fluid", "Fluid (follows width)"),
+    ("menu.theme", "Theme"),
+    ("menu.clear_custom_theme", "Clear custom theme"),
+    ("menu.zoom", "Zoom"),
+    ("menu.reset_zoom", "Reset zoom"),
+    ("menu.layout_direction", "Layout direction"),
+    ("menu.language", "Language"),
+    ("menu.help", "Help"),
+    ("menu.help_hint", "Demo showing responsive layouts"),
+    ("menu.keyboard_shortcuts", "Keyboard Shortcuts"),
+    // Values shown in semantic labels
+    ("layout.stacked", "Stacked"),
+    ("layout.side_central", "Side+Central"),
+    ("bucket.small", "Small"),
+    ("bucket.medium", "Medium"),
+    ("bucket.large", "Large"),
+    ("direction.left_to_right", "Left to right"),
+    ("direction.right_to_left", "Right to left"),
+    ("scaling.zoom", "Zoom"),
+    ("scaling.style", "Style"),
+    ("scaling.fluid", "Fluid"),
+    ("theme.system", "System"),
+    ("theme.light", "Light"),
+    ("theme.dark", "Dark"),
+    ("theme.high_contrast", "High Contrast"),
+    ("sort.manual", "Manual"),
+    ("sort.title_ascending", "Title A–Z"),
+    ("sort.title_descending", "Title Z–A"),
+    ("sort.newest_first", "Newest first"),
+    ("sort.oldest_first", "Oldest first"),
+    ("common.yes", "Yes"),
+    ("common.no", "No"),
+    ("confirm.dismissed", "Dismissed"),
+    // Status labels
+    ("status.layout", "Layout: {mode}"),
+    ("status.scale", "Scale: {percent}%"),
+    (
+        "status.scale_user_zoom",
+        "Scale: {percent}% (user zoom {user}%)",
+    ),
+    ("status.scaling_mode", "Scaling mode: {mode}"),
+    ("status.scale_bucket", "Scale bucket: {bucket}"),
+    ("status.theme", "Theme: {theme}"),
+    ("status.custom_theme", "Custom theme: {name}"),
+    ("status.document", "Document: {name}"),
+    // Filters
+    ("filters.heading", "Filters"),
+    ("filters.search", "Search cards"),
+    ("filters.tags", "Tags"),
+    ("filters.sort", "Sort"),
+    ("filters.clear", "Clear filters"),
+    (
+        "filters.hint",
+        "Use the central area to interact with the app",
+    ),
+    // Form
+    ("form.name", "Name"),
+    ("form.age", "Age"),
+    ("field.name", "Name"),
+    ("field.age", "Age"),
+    ("validation.required", "{field} is required"),
+    (
+        "validation.max_length.one",
+        "{field} must be at most {count} character",
+    ),
+    (
+        "validation.max_length.other",
+        "{field} must be at most {count} characters",
+    ),
+    ("validation.invalid_char", "{field} cannot contain '{char}'"),
+    (
+        "validation.range",
+        "{field} must be between {min} and {max}",
+    ),
+    ("counter.increment", "Increment"),
+    ("counter.decrement", "Decrement"),
+    ("counter.value", "Counter: {count}"),
+    ("greeting.one", "Hello, {name}! You are {count} year old."),
+    (
+        "greeting.other",
+        "Hello, {name}! You are {count} years old.",
+    ),
+    // Dialogs
+    ("dialog.show", "Show Dialog"),
+    ("dialog.last_choice", "Last choice: {choice}"),
+    ("dialog.confirm_title", "Confirmation"),
+    (
+        "dialog.confirm_message",
+        "Are you sure you want to continue?",
+    ),
+    ("dialog.save_title", "Save changes?"),
+    (
+        "dialog.save_message",
+        "Do you want to save changes to {name}?",
+    ),
+    ("dialog.save", "Save"),
+    ("dialog.discard", "Discard"),
+    ("dialog.cancel", "Cancel"),
+    // Cards
+    ("cards.columns", "Columns: {count}"),
+    ("cards.showing.one", "Showing {shown} of {count} card"),
+    ("cards.showing.other", "Showing {shown} of {count} cards"),
+    ("cards.last_action", "Last action: {status}"),
+    ("cards.empty", "No cards yet"),
+    ("cards.no_match", "No cards match the filters"),
+    ("card.pinned", "Pinned"),
+    ("card.action", "Action"),
+    ("card.more", "More"),
+    ("card.details", "Details"),
+    ("card.duplicate", "Duplicate"),
+    ("card.delete", "Delete"),
+    ("card.pin", "Pin"),
+    ("card.unpin", "Unpin"),
+    ("card.control_label", "{control} for {title}"),
+    ("card.control_label_id", "{control} for {title} (#{id})"),
+    ("card.copy_title", "{title} (copy)"),
+    ("card.action_ran", "Ran action for {title}"),
+    ("details.window", "{title} details"),
+    ("details.title", "Title: {value}"),
+    ("details.body", "Body: {value}"),
+    ("details.tags", "Tags: {value}"),
+    ("details.no_tags", "none"),
+    ("details.created", "Created: {value}"),
+    ("details.pinned", "Pinned: {value}"),
+    ("details.id", "Id: {value}"),
+    // Keyboard shortcuts
+    ("shortcuts.either", "{first} or {second}"),
+    ("shortcut.undo", "Undo the last edit"),
+    ("shortcut.redo", "Redo the last undone edit"),
+    ("shortcut.increment", "Increment the counter"),
+    ("shortcut.decrement", "Decrement the counter"),
+    ("shortcut.show_dialog", "Show the confirmation dialog"),
+    ("shortcut.toggle_scaling", "Toggle the scaling mode"),
+    ("shortcut.focus_search", "Focus the card search"),
+    ("shortcut.zoom_in", "Zoom in"),
+    ("shortcut.zoom_out", "Zoom out"),
+    ("shortcut.reset_zoom", "Reset zoom"),
+];
+
+const DE: &[(&str, &str)] = &[
+    ("app.title", "egui_kittest Demo-App"),
+    ("app.window_title", "{document} — egui_kittest Demo"),
+    ("document.untitled", "Unbenannt"),
+    ("error.open", "{path} konnte nicht geöffnet werden: {error}"),
+    (
+        "error.load_cards",
+        "Karten aus {path} konnten nicht geladen werden: {error}",
+    ),
+    (
+        "error.save",
+        "{path} konnte nicht gespeichert werden: {error}",
+    ),
+    (
+        "error.form_invalid",
+        "Behebe vor dem Speichern die Fehler im Formular",
+    ),
+    ("error.io", "Ein-/Ausgabefehler: {error}"),
+    ("error.io.not_found", "Datei nicht gefunden"),
+    ("error.io.permission_denied", "Zugriff verweigert"),
+    ("error.invalid_document", "ungültiges Dokument: {error}"),
+    (
+        "error.unsupported_version",
+        "Dokumentversion {version} ist neuer als unterstützt",
+    ),
+    ("error.duplicate_card_id", "doppelte Karten-ID {id}"),
+    ("error.invalid_cards", "ungültige Kartendaten: {error}"),
+    ("error.theme_file", "{path}: {error}"),
+    ("error.invalid_theme", "ungültige Designdatei: {error}"),
+    (
+        "error.theme_stroke_width",
+        "ungültige Designdatei: stroke_width darf nicht negativ sein, gefunden {value}",
+    ),
+    (
+        "error.theme_font_size",
+        "ungültige Designdatei: font_sizes.{field} muss positiv sein, gefunden {size}",
+    ),
+    // Menus
+    ("menu.file", "Datei"),
+    ("menu.new", "Neu"),
+    ("menu.open", "Öffnen"),
+    ("menu.save", "Speichern"),
+    ("menu.edit", "Bearbeiten"),
+    ("menu.undo", "Rückgängig"),
+    ("menu.redo", "Wiederholen"),
+    ("menu.view", "Ansicht"),
+    (
+        "menu.view_hint",
+        "Das Layout passt sich der Fensterbreite an",
+    ),
+    ("menu.scaling_strategy", "Skalierungsstrategie"),
+    ("menu.scaling_zoom", "Zoom-basiert"),
+    ("menu.scaling_style", "Stil-basiert"),
+    ("menu.scaling_fluid", "Fließend (folgt der Breite)"),
+    ("menu.theme", "Design"),
+    ("menu.clear_custom_theme", "Eigenes Design entfernen"),
+    ("menu.zoom", "Zoom"),
+    ("menu.reset_zoom", "Zoom zurücksetzen"),
+    ("menu.layout_direction", "Layoutrichtung"),
+    ("menu.language", "Sprache"),
+    ("menu.help", "Hilfe"),
+    ("menu.help_hint", "Demo für responsive Layouts"),
+    ("menu.keyboard_shortcuts", "Tastenkürzel"),
+    // Values shown in semantic labels
+    ("layout.stacked", "Gestapelt"),
+    ("layout.side_central", "Seite+Mitte"),
+    ("bucket.small", "Klein"),
+    ("bucket.medium", "Mittel"),
+    ("bucket.large", "Groß"),
+    ("direction.left_to_right", "Von links nach rechts"),
+    ("direction.right_to_left", "Von rechts nach links"),
+    ("scaling.zoom", "Zoom"),
+    ("scaling.style", "Stil"),
+    ("scaling.fluid", "Fließend"),
+    ("theme.system", "System"),
+    ("theme.light", "Hell"),
+    ("theme.dark", "Dunkel"),
+    ("theme.high_contrast", "Hoher Kontrast"),
+    ("sort.manual", "Manuell"),
+    ("sort.title_ascending", "Titel A–Z"),
+    ("sort.title_descending", "Titel Z–A"),
+    ("sort.newest_first", "Neueste zuerst"),
+    ("sort.oldest_first", "Älteste zuerst"),
+    ("common.yes", "Ja"),
+    ("common.no", "Nein"),
+    ("confirm.dismissed", "Verworfen"),
+    // Status labels
+    ("status.layout", "Layout: {mode}"),
+    ("status.scale", "Skalierung: {percent} %"),
+    (
+        "status.scale_user_zoom",
+        "Skalierung: {percent} % (eigener Zoom {user} %)",
+    ),
+    ("status.scaling_mode", "Skalierungsmodus: {mode}"),
+    ("status.scale_bucket", "Skalierungsstufe: {bucket}"),
+    ("status.theme", "Design: {theme}"),
+    ("status.custom_theme", "Eigenes Design: {name}"),
+    ("status.document", "Dokument: {name}"),
+    // Filters
+    ("filters.heading", "Filter"),
+    ("filters.search", "Karten durchsuchen"),
+    ("filters.tags", "Schlagwörter"),
+    ("filters.sort", "Sortierung"),
+    ("filters.clear", "Filter zurücksetzen"),
+    (
+        "filters.hint",
+        "Im mittleren Bereich kannst du mit der App arbeiten",
+    ),
+    // Form
+    ("form.name", "Name"),
+    ("form.age", "Alter"),
+    ("field.name", "Name"),
+    ("field.age", "Alter"),
+    ("validation.required", "{field} ist erforderlich"),
+    (
+        "validation.max_length.one",
+        "{field} darf höchstens {count} Zeichen lang sein",
+    ),
+    (
+        "validation.max_length.other",
+        "{field} darf höchstens {count} Zeichen lang sein",
+    ),
+    (
+        "validation.invalid_char",
+        "{field} darf kein '{char}' enthalten",
+    ),
+    (
+        "validation.range",
+        "{field} muss zwischen {min} und {max} liegen",
+    ),
+    ("counter.increment", "Erhöhen"),
+    ("counter.decrement", "Verringern"),
+    ("counter.value", "Zähler: {count}"),
+    ("greeting.one", "Hallo, {name}! Du bist {count} Jahr alt."),
+    (
+        "greeting.other",
+        "Hallo, {name}! Du bist {count} Jahre alt.",
+    ),
+    // Dialogs
+    ("dialog.show", "Dialog anzeigen"),
+    ("dialog.last_choice", "Letzte Wahl: {choice}"),
+    ("dialog.confirm_title", "Bestätigung"),
+    ("dialog.confirm_message", "Möchtest du wirklich fortfahren?"),
+    ("dialog.save_title", "Änderungen speichern?"),
+    (
+        "dialog.save_message",
+        "Möchtest du die Änderungen an {name} speichern?",
+    ),
+    ("dialog.save", "Speichern"),
+    ("dialog.discard", "Verwerfen"),
+    ("dialog.cancel", "Abbrechen"),
+    // Cards
+    ("cards.columns", "Spalten: {count}"),
+    ("cards.showing.one", "{shown} von {count} Karte angezeigt"),
+    (
+        "cards.showing.other",
+        "{shown} von {count} Karten angezeigt",
+    ),
+    ("cards.last_action", "Letzte Aktion: {status}"),
+    ("cards.empty", "Noch keine Karten"),
+    ("cards.no_match", "Keine Karte passt zu den Filtern"),
+    ("card.pinned", "Angeheftet"),
+    ("card.action", "Aktion"),
+    ("card.more", "Mehr"),
+    ("card.details", "Details"),
+    ("card.duplicate", "Duplizieren"),
+    ("card.delete", "Löschen"),
+    ("card.pin", "Anheften"),
+    ("card.unpin", "Lösen"),
+    ("card.control_label", "{control} für {title}"),
+    ("card.control_label_id", "{control} für {title} (#{id})"),
+    ("card.copy_title", "{title} (Kopie)"),
+    ("card.action_ran", "Aktion für {title} ausgeführt"),
+    ("details.window", "Details zu {title}"),
+    ("details.title", "Titel: {value}"),
+    ("details.body", "Text: {value}"),
+    ("details.tags", "Schlagwörter: {value}"),
+    ("details.no_tags", "keine"),
+    ("details.created", "Erstellt: {value}"),
+    ("details.pinned", "Angeheftet: {value}"),
+    ("details.id", "ID: {value}"),
+    // Keyboard shortcuts
+    ("shortcuts.either", "{first} oder {second}"),
+    ("shortcut.undo", "Letzte Änderung rückgängig machen"),
+    ("shortcut.redo", "Rückgängig gemachte Änderung wiederholen"),
+    ("shortcut.increment", "Zähler erhöhen"),
+    ("shortcut.decrement", "Zähler verringern"),
+    ("shortcut.show_dialog", "Bestätigungsdialog anzeigen"),
+    ("shortcut.toggle_scaling", "Skalierungsmodus wechseln"),
+    ("shortcut.focus_search", "Kartensuche fokussieren"),
+    ("shortcut.zoom_in", "Vergrößern"),
+    ("shortcut.zoom_out", "Verkleinern"),
+    ("shortcut.reset_zoom", "Zoom zurücksetzen"),
+];
@@ -0,0 +1,368 @@
+//! Pure breakpoint model for the responsive shell.
+//!
+//! Everything in here works on plain numbers (physical pixels, points and
+//! pixels-per-point) so layout choices can be unit-tested without a `Harness`.
+
+use crate::ScalingMode;
+use crate::i18n::Locale;
+
+/// Width thresholds and panel limits that drive the responsive layout.
+///
+/// Window thresholds are in physical pixels (points × pixels_per_point) so decisions
+/// stay stable across DPI and zoom. Panel limits are in logical points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoints {
+    /// Below this width the layout stacks and the grid shows a single column.
+    pub medium_px: f32,
+    /// At or above this width the grid shows three columns.
+    pub large_px: f32,
+    /// At or above this width scaling uses its largest step.
+    pub extra_large_px: f32,
+    /// Minimum central width (points) before the side panel is folded into the stack.
+    pub central_min_points: f32,
+    /// Left panel width (points) assumed until the panel has been measured.
+    pub default_left_points: f32,
+    /// Dead bands that keep decisions from flapping while the window is resized.
+    pub hysteresis: Hysteresis,
+    /// Width range and scale limits for [`ScalingMode::Fluid`].
+    pub fluid: FluidScale,
+}
+
+/// Width of the dead band around each threshold, centred on the threshold.
+///
+/// A decision only changes once the input has moved half a band past the
+/// threshold, so slow drags across a breakpoint switch exactly once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hysteresis {
+    /// Band around `medium_px` for the stacking decision (physical px).
+    pub stack_px: f32,
+    /// Band around `central_min_points` for the stacking decision (points).
+    pub central_points: f32,
+    /// Band around the column-count thresholds (physical px).
+    pub columns_px: f32,
+    /// Band around the scale steps and `Scale bucket` thresholds (physical px).
+    pub scale_px: f32,
+}
+
+impl Default for Hysteresis {
+    fn default() -> Self {
+        Self {
+            stack_px: 32.0,
+            central_points: 24.0,
+            columns_px: 32.0,
+            scale_px: 32.0,
+        }
+    }
+}
+
+impl Hysteresis {
+    /// No dead bands: every decision switches exactly at its threshold.
+    pub const NONE: Self = Self {
+        stack_px: 0.0,
+        central_points: 0.0,
+        columns_px: 0.0,
+        scale_px: 0.0,
+    };
+}
+
+/// Continuous scale for [`ScalingMode::Fluid`]: linear in physical width between
+/// `min_px` and `max_px`, clamped outside that range and rounded to `step`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FluidScale {
+    pub min_px: f32,
+    pub max_px: f32,
+    pub min_scale: f32,
+    pub max_scale: f32,
+    /// Scales are multiples of this, so a one-pixel resize rarely changes the zoom.
+    pub step: f32,
+}
+
+impl Default for FluidScale {
+    fn default() -> Self {
+        // Same range as the Zoom steps, from phone width to the extra-large breakpoint
+        Self {
+            min_px: 360.0,
+            max_px: 1280.0,
+            min_scale: 0.85,
+            max_scale: 1.5,
+            step: 0.05,
+        }
+    }
+}
+
+impl FluidScale {
+    /// Scale for a window of `window_px` physical pixels.
+    ///
+    /// With the `previous` scale, the result only changes once the unrounded value is
+    /// three quarters of a step away from it, so a width sitting on a rounding boundary
+    /// can't flip between two steps.
+    pub fn scale(&self, window_px: f32, previous: Option<f32>) -> f32 {
+        let span = (self.max_px - self.min_px).max(f32::EPSILON);
+        let t = ((window_px - self.min_px) / span).clamp(0.0, 1.0);
+        let raw = self.min_scale + (self.max_scale - self.min_scale) * t;
+        if let Some(previous) = previous
+            && (raw - previous).abs() < self.step * 0.75
+        {
+            return previous;
+        }
+        if self.step > 0.0 {
+            (raw / self.step).round() * self.step
+        } else {
+            raw
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            medium_px: 600.0,
+            large_px: 900.0,
+            extra_large_px: 1280.0,
+            central_min_points: 460.0,
+            default_left_points: 280.0,
+            hysteresis: Hysteresis::default(),
+            fluid: FluidScale::default(),
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Classify a physical window width.
+    pub fn size_class(&self, window_px: f32) -> SizeClass {
+        SizeClass::from_level(self.level(window_px, None, 0.0))
+    }
+
+    /// Number of size thresholds at or below `window_px`.
+    ///
+    /// With a `previous` level, thresholds are shifted by half of `band` away from the
+    /// current level so the result only changes once the width is clearly past them.
+    fn level(&self, window_px: f32, previous: Option<usize>, band: f32) -> usize {
+        let thresholds = [self.medium_px, self.large_px, self.extra_large_px];
+        thresholds
+            .iter()
+            .enumerate()
+            .filter(|&(i, &threshold)| {
+                let above = previous.is_some_and(|level| level > i);
+                crosses(window_px, threshold, previous.map(|_| above), band)
+            })
+            .count()
+    }
+}
+
+/// Whether `value` counts as at-or-above `threshold`.
+///
+/// `was_above` is the previous answer, if any; it moves the effective threshold
+/// half a band down (stay above) or up (stay below).
+fn crosses(value: f32, threshold: f32, was_above: Option<bool>, band: f32) -> bool {
+    match was_above {
+        None => value >= threshold,
+        Some(true) => value >= threshold - band * 0.5,
+        Some(false) => value >= threshold + band * 0.5,
+    }
+}
+
+/// Width class of the window, one per scaling step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SizeClass {
+    Small,
+    Medium,
+    Large,
+    ExtraLarge,
+}
+
+impl SizeClass {
+    fn from_level(level: usize) -> Self {
+        match level {
+            0 => SizeClass::Small,
+            1 => SizeClass::Medium,
+            2 => SizeClass::Large,
+            _ => SizeClass::ExtraLarge,
+        }
+    }
+
+    fn level(self) -> usize {
+        self as usize
+    }
+}
+
+/// Whether filters and main content share a row or are stacked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LayoutMode {
+    Stacked,
+    SideCentral,
+}
+
+impl LayoutMode {
+    /// Message key of the text used in the `Layout: …` semantic label.
+    pub fn key(self) -> &'static str {
+        match self {
+            LayoutMode::Stacked => "layout.stacked",
+            LayoutMode::SideCentral => "layout.side_central",
+        }
+    }
+
+    /// English text used in the `Layout: …` semantic label.
+    pub fn label(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+}
+
+/// Coarse bucket exposed as `Scale bucket: …`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScaleBucket {
+    Small,
+    Medium,
+    Large,
+}
+
+impl ScaleBucket {
+    /// Message key of the text used in the `Scale bucket: …` semantic label.
+    pub fn key(self) -> &'static str {
+        match self {
+            ScaleBucket::Small => "bucket.small",
+            ScaleBucket::Medium => "bucket.medium",
+            ScaleBucket::Large => "bucket.large",
+        }
+    }
+
+    /// English text used in the `Scale bucket: …` semantic label.
+    pub fn label(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+}
+
+/// Reading direction of the shell: which side the filters panel sits on and which
+/// way rows and grid columns run.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    /// Mirrored for right-to-left scripts: filters on the right, rows and grid
+    /// columns running right to left.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub const ALL: [LayoutDirection; 2] =
+        [LayoutDirection::LeftToRight, LayoutDirection::RightToLeft];
+
+    pub fn is_right_to_left(self) -> bool {
+        self == LayoutDirection::RightToLeft
+    }
+
+    /// Message key of the direction's name in View → Layout direction.
+    pub fn key(self) -> &'static str {
+        match self {
+            LayoutDirection::LeftToRight => "direction.left_to_right",
+            LayoutDirection::RightToLeft => "direction.right_to_left",
+        }
+    }
+
+    /// English text for [`LayoutDirection::key`].
+    pub fn label(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+}
+
+impl From<SizeClass> for ScaleBucket {
+    fn from(class: SizeClass) -> Self {
+        match class {
+            SizeClass::Small => ScaleBucket::Small,
+            SizeClass::Medium => ScaleBucket::Medium,
+            SizeClass::Large | SizeClass::ExtraLarge => ScaleBucket::Large,
+        }
+    }
+}
+
+/// Everything the shell needs to know to lay out one frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutDecision {
+    pub mode: LayoutMode,
+    pub columns: usize,
+    pub bucket: ScaleBucket,
+    pub size_class: SizeClass,
+}
+
+impl LayoutDecision {
+    /// Decide the layout for a window of `window_px` physical pixels.
+    ///
+    /// `left_panel_px` is the side panel width measured on a previous frame (physical px);
+    /// when absent, [`Breakpoints::default_left_points`] is used to predict the central width.
+    pub fn compute(
+        breakpoints: &Breakpoints,
+        window_px: f32,
+        pixels_per_point: f32,
+        left_panel_px: Option<f32>,
+    ) -> Self {
+        Self::compute_with_previous(
+            breakpoints,
+            window_px,
+            pixels_per_point,
+            left_panel_px,
+            None,
+        )
+    }
+
+    /// Like [`LayoutDecision::compute`], but applies [`Breakpoints::hysteresis`] relative to
+    /// the decision made on the previous frame.
+    pub fn compute_with_previous(
+        breakpoints: &Breakpoints,
+        window_px: f32,
+        pixels_per_point: f32,
+        left_panel_px: Option<f32>,
+        previous: Option<&LayoutDecision>,
+    ) -> Self {
+        let bands = &breakpoints.hysteresis;
+        let size_class = SizeClass::from_level(breakpoints.level(
+            window_px,
+            previous.map(|p| p.size_class.level()),
+            bands.scale_px,
+        ));
+        let columns =
+            match breakpoints.level(window_px, previous.map(|p| p.columns - 1), bands.columns_px) {
+                0 => 1,
+                1 => 2,
+                _ => 3,
+            };
+
+        // Stack when the window is small, or when a wide left panel would leave the
+        // central area too narrow. Central width is compared in points so expectations
+        // at specific window sizes stay stable across pixels-per-point.
+        let window_points = window_px / pixels_per_point;
+        let predicted_left_points = left_panel_px
+            .map(|px| px / pixels_per_point)
+            .unwrap_or(breakpoints.default_left_points);
+        let predicted_central_points = (window_points - predicted_left_points).max(0.0);
+        let was_side = previous.map(|p| !p.is_stacked());
+        let stacked = !crosses(window_px, breakpoints.medium_px, was_side, bands.stack_px)
+            || !crosses(
+                predicted_central_points,
+                breakpoints.central_min_points,
+                was_side,
+                bands.central_points,
+            );
+
+        Self {
+            mode: if stacked {
+                LayoutMode::Stacked
+            } else {
+                LayoutMode::SideCentral
+            },
+            columns,
+            bucket: size_class.into(),
+            size_class,
+        }
+    }
+
+    pub fn is_stacked(&self) -> bool {
+        self.mode == LayoutMode::Stacked
+    }
+
+    /// Visual scale for this decision under the given scaling mode.
+    pub fn scale(&self, mode: ScalingMode) -> f32 {
+        mode.scale_for(self.size_class)
+    }
+}
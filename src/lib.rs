@@ -1,312 +1,1456 @@
+use std::cell::Cell;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
 use eframe::egui;
 
-#[derive(Clone, Copy, PartialEq, Eq, Default)]
+mod cards;
+mod dialogs;
+mod document;
+mod filters;
+mod grid;
+mod history;
+mod i18n;
+mod layout;
+mod persistence;
+mod scaling;
+mod shortcuts;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod theme;
+mod validation;
+
+pub use cards::{
+    AnnounceCard, Card, CardCommand, CardSource, CardSourceError, FileCardSource, StaticCards,
+};
+pub use dialogs::{ConfirmOutcome, ConfirmationDialog, SaveChangesDialog, SaveChoice};
+pub use document::{DOCUMENT_VERSION, Document, DocumentError, FilePicker, FixedPathPicker};
+pub use filters::{FilterState, FilteredCards, SortOrder};
+pub use grid::{CardGrid, GridStats, PLACEHOLDER_ROWS};
+pub use history::{DEFAULT_HISTORY_LIMIT, Edit, History};
+pub use i18n::{Locale, PluralCategory};
+pub use layout::{
+    Breakpoints, FluidScale, Hysteresis, LayoutDecision, LayoutDirection, LayoutMode, ScaleBucket,
+    SizeClass,
+};
+pub use persistence::{PREFERENCES_KEY, PREFERENCES_VERSION, Preferences};
+pub use scaling::{StyleScaler, scale_style};
+pub use shortcuts::{ShortcutAction, ShortcutConflict, ShortcutRegistry};
+pub use theme::{
+    FontSizes, HexColor, THEME_POLL_INTERVAL, Theme, ThemeError, ThemeFile, ThemeFileWatch,
+    ThemeWidgets, WidgetColors,
+};
+pub use validation::{
+    AGE_RANGE, FieldRules, FieldValue, FormErrors, FormValidator, Rule, TouchedFields,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub enum ScalingMode {
     #[default]
     Zoom,
     Style,
+    /// Zoom that follows the window width continuously, see [`FluidScale`].
+    Fluid,
+}
+
+impl ScalingMode {
+    /// Discrete scale step for a window size class.
+    ///
+    /// `Fluid` has no steps of its own and reports the `Zoom` steps here; the app uses
+    /// [`FluidScale::scale`] for it instead.
+    pub fn scale_for(self, size_class: SizeClass) -> f32 {
+        match (self, size_class) {
+            // Discrete, stronger zoom mapping to avoid oscillations
+            (ScalingMode::Zoom | ScalingMode::Fluid, SizeClass::Small) => 0.85,
+            (ScalingMode::Zoom | ScalingMode::Fluid, SizeClass::Medium) => 1.0,
+            (ScalingMode::Zoom | ScalingMode::Fluid, SizeClass::Large) => 1.25,
+            (ScalingMode::Zoom | ScalingMode::Fluid, SizeClass::ExtraLarge) => 1.50,
+            // Style-driven scaling (typography + spacing)
+            (ScalingMode::Style, SizeClass::Small) => 0.95,
+            (ScalingMode::Style, SizeClass::Medium) => 1.15,
+            (ScalingMode::Style, SizeClass::Large) => 1.35,
+            (ScalingMode::Style, SizeClass::ExtraLarge) => 1.60,
+        }
+    }
+
+    /// Message key of the text used in the `Scaling mode: …` semantic label.
+    pub fn key(self) -> &'static str {
+        match self {
+            ScalingMode::Zoom => "scaling.zoom",
+            ScalingMode::Style => "scaling.style",
+            ScalingMode::Fluid => "scaling.fluid",
+        }
+    }
+
+    /// English text for [`ScalingMode::key`].
+    pub fn label(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+}
+
+/// File action that discards the current document and may need confirmation first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingAction {
+    New,
+    Open,
+    Close,
+}
+
+/// Allowed values for [`DemoApp::user_zoom`].
+pub const USER_ZOOM_RANGE: std::ops::RangeInclusive<f32> = 0.5..=3.0;
+/// How much Ctrl+Plus / Ctrl+Minus change [`DemoApp::user_zoom`].
+pub const USER_ZOOM_STEP: f32 = 0.1;
+
+/// Id source of the card search box, which the Focus Search shortcut targets.
+const CARD_SEARCH_ID: &str = "card_search";
+
+/// Per-card control activated this frame, applied once the grid is drawn.
+enum CardEvent {
+    Action(u64),
+    Details(u64),
+    Duplicate(u64),
+    Delete(u64),
+    TogglePin(u64),
 }
 
-#[derive(Default)]
 pub struct DemoApp {
     pub name: String,
     pub age: u32,
     pub counter: i32,
+    /// Key combos and the actions they trigger.
+    pub shortcuts: ShortcutRegistry,
+    /// Whether Help → Keyboard Shortcuts is open.
+    pub show_shortcuts: bool,
+    /// Undo/redo steps for edits made through the UI.
+    pub history: History,
+    /// Rules for the Name/Age form; the form must be valid to save.
+    pub validator: FormValidator,
+    /// Form fields whose errors are shown; set once edited, or all on a save attempt.
+    pub touched: TouchedFields,
     pub show_confirmation_dialog: bool,
+    /// How the Confirmation dialog was last closed.
+    pub last_confirmation: Option<ConfirmOutcome>,
+    /// Called with each Confirmation dialog outcome.
+    pub on_confirmation: Option<Box<dyn FnMut(ConfirmOutcome)>>,
+    /// Effective visual scale last applied, zoom- or style-based.
     pub zoom_factor: f32,
     pub scaling_mode: ScalingMode,
-    pub base_style: Option<egui::Style>,
-    // Track the left panel width (in physical px) to inform stacking decisions
+    /// User zoom multiplier on top of the adaptive scale; 1.0 means none.
+    pub user_zoom: f32,
+    /// Base style and its scaled copies for [`ScalingMode::Style`].
+    pub style_scaler: StyleScaler,
+    pub theme: Theme,
+    /// Language of the UI text, chosen in View → Language.
+    pub locale: Locale,
+    /// Which side the filters panel is on and which way rows and grid columns run.
+    pub layout_direction: LayoutDirection,
+    /// Theme file applied on top of [`DemoApp::theme`].
+    pub custom_theme: Option<ThemeFile>,
+    /// Why the watched theme file could not be loaded.
+    pub theme_error: Option<String>,
+    // Track the side panel width (in physical px) to inform stacking decisions
     pub left_panel_width_px: Option<f32>,
+    /// Thresholds used for stacking, column count and scale buckets.
+    pub breakpoints: Breakpoints,
+    /// Decision made on the most recent frame; feeds hysteresis on the next one.
+    pub layout: Option<LayoutDecision>,
+    /// Number of frames on which the layout decision changed.
+    pub layout_transitions: u32,
+    /// Read with [`DemoApp::cards`]; changes go through [`DemoApp::cards_mut`] so
+    /// they bump `cards_revision`.
+    cards: Vec<Card>,
+    /// Bumped on every change to `cards`, so per-frame checks don't compare the set.
+    cards_revision: u64,
+    /// Tag, search and sort state applied to the card grid.
+    pub filters: FilterState,
+    /// The grid's card order and the Filters panel's tags, recomputed on change.
+    pub filtered_cards: FilteredCards,
+    /// Virtualized grid state; `card_grid.stats` reports what the last frame laid out.
+    pub card_grid: CardGrid,
+    /// Run by each card's "Action" button.
+    pub card_command: Box<dyn CardCommand>,
+    /// Message from the last card command.
+    pub card_status: Option<String>,
+    /// Ids of cards whose detail window is open.
+    pub open_card_details: BTreeSet<u64>,
+    /// Where the current document was last opened from or saved to.
+    pub document_path: Option<PathBuf>,
+    /// Chooses paths for File → Open / Save.
+    pub file_picker: Box<dyn FilePicker>,
+    /// Error from the last File operation, shown until the next one succeeds.
+    pub file_error: Option<String>,
+    /// Action waiting for an answer to the "Save changes?" dialog.
+    pub pending_action: Option<PendingAction>,
+    /// Set when the Confirmation dialog closes, until focus is back on "Show Dialog".
+    refocus_show_dialog: bool,
+    /// Theme file reloaded when it changes on disk.
+    theme_watch: Option<ThemeFileWatch>,
+    /// The context's style before any theme was applied.
+    unthemed_style: Option<egui::Style>,
+    /// Theme, resolved dark/light theme and custom theme behind the scaler's base style.
+    applied_theme: Option<(Theme, egui::Theme, Option<ThemeFile>)>,
+    /// Fluid scale chosen on the previous frame, for [`FluidScale::scale`]'s dead band.
+    fluid_scale: Option<f32>,
+    /// Set once the user agreed to close, so the next close request goes through.
+    close_confirmed: bool,
+    /// Set when the user discarded their changes to close; [`Preferences`] then keeps
+    /// the saved content rather than the discarded edits.
+    discarded_on_close: bool,
+    /// Content as last opened or saved; the document is dirty when the app differs from it.
+    saved_document: Document,
+    /// Cards revision last compared with `saved_document`, and whether they matched.
+    saved_cards_match: Cell<(u64, bool)>,
+    /// Title last sent to the viewport, to avoid re-sending it every frame.
+    window_title: String,
+}
+
+impl Default for DemoApp {
+    fn default() -> Self {
+        let document = Document::default();
+        Self {
+            name: document.name.clone(),
+            age: document.age,
+            counter: document.counter,
+            shortcuts: ShortcutRegistry::standard(),
+            show_shortcuts: false,
+            history: History::default(),
+            validator: FormValidator::default(),
+            touched: TouchedFields::default(),
+            show_confirmation_dialog: false,
+            last_confirmation: None,
+            on_confirmation: None,
+            zoom_factor: 0.0,
+            scaling_mode: ScalingMode::default(),
+            user_zoom: 1.0,
+            style_scaler: StyleScaler::default(),
+            theme: Theme::default(),
+            locale: Locale::default(),
+            layout_direction: LayoutDirection::default(),
+            custom_theme: None,
+            theme_error: None,
+            theme_watch: None,
+            unthemed_style: None,
+            left_panel_width_px: None,
+            breakpoints: Breakpoints::default(),
+            layout: None,
+            layout_transitions: 0,
+            cards: document.cards.clone(),
+            cards_revision: 0,
+            filters: FilterState::default(),
+            filtered_cards: FilteredCards::default(),
+            card_grid: CardGrid::default(),
+            card_command: Box::new(AnnounceCard),
+            card_status: None,
+            open_card_details: BTreeSet::new(),
+            document_path: None,
+            file_picker: Box::new(FixedPathPicker::default()),
+            file_error: None,
+            pending_action: None,
+            refocus_show_dialog: false,
+            applied_theme: None,
+            fluid_scale: None,
+            close_confirmed: false,
+            discarded_on_close: false,
+            saved_document: document,
+            saved_cards_match: Cell::new((0, true)),
+            window_title: String::new(),
+        }
+    }
 }
 
 impl DemoApp {
     pub fn new() -> Self {
-    Self { zoom_factor: 1.0, scaling_mode: ScalingMode::default(), base_style: None, ..Default::default() }
+        Self {
+            zoom_factor: 1.0,
+            scaling_mode: ScalingMode::default(),
+            ..Default::default()
+        }
     }
-}
 
-impl eframe::App for DemoApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Capture baseline style once for style-based scaling
-        if self.base_style.is_none() {
-            self.base_style = Some((*ctx.style()).clone());
-        }
-
-    // Measure unscaled window width (in points) and base pixels-per-point once per frame
-    // Use physical width (points * ppp) for stable, DPI-independent breakpoints
-    let unscaled_points = ctx.available_rect().width();
-    let base_ppp = ctx.pixels_per_point();
-    // Treat pixels_per_point as a stable proxy for physical scaling captured before zoom changes
-    let window_px = unscaled_points * base_ppp;
-    // Adaptive scaling based directly on current window width
-    let win_width = window_px;
-    match self.scaling_mode {
-            ScalingMode::Zoom => {
-        // Discrete, stronger zoom mapping to avoid oscillations
-        let desired = if win_width < 600.0 { 0.85 } else if win_width < 900.0 { 1.0 } else if win_width < 1280.0 { 1.25 } else { 1.50 };
-                let eps = 0.01;
-                if (self.zoom_factor - desired).abs() > eps {
-                    self.zoom_factor = desired;
-                    ctx.set_zoom_factor(self.zoom_factor);
+    /// Create the app, restoring [`Preferences`] from `storage` when available.
+    ///
+    /// Pass `cc.storage` from the [`eframe::CreationContext`].
+    pub fn from_storage(storage: Option<&dyn eframe::Storage>) -> Self {
+        let mut app = Self::new();
+        if let Some(prefs) = storage.and_then(Preferences::load) {
+            prefs.apply_to(&mut app);
+        }
+        app
+    }
+
+    /// Whether the document has changed since it was last opened or saved.
+    pub fn is_dirty(&self) -> bool {
+        let saved = &self.saved_document;
+        saved.name != self.name
+            || saved.age != self.age
+            || saved.counter != self.counter
+            || !self.cards_match_saved()
+    }
+
+    // The card sets are compared at most once per revision, not on every frame
+    fn cards_match_saved(&self) -> bool {
+        let (revision, matched) = self.saved_cards_match.get();
+        if revision == self.cards_revision {
+            return matched;
+        }
+        let matched = self.cards == self.saved_document.cards;
+        self.saved_cards_match.set((self.cards_revision, matched));
+        matched
+    }
+
+    /// Treat the current content as saved.
+    pub fn mark_clean(&mut self) {
+        self.saved_document = Document::from_app(self);
+        self.saved_cards_match.set((self.cards_revision, true));
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// The card set, for changing it; any access counts as a change.
+    pub fn cards_mut(&mut self) -> &mut Vec<Card> {
+        self.cards_revision += 1;
+        &mut self.cards
+    }
+
+    pub fn set_cards(&mut self, cards: Vec<Card>) {
+        *self.cards_mut() = cards;
+    }
+
+    /// Document file name, or `Untitled` (in [`DemoApp::locale`]) before the first save.
+    pub fn document_name(&self) -> String {
+        self.document_path
+            .as_deref()
+            .and_then(|path| path.file_name())
+            .map_or_else(
+                || self.locale.text("document.untitled").to_owned(),
+                |name| name.to_string_lossy().into_owned(),
+            )
+    }
+
+    /// [`DemoApp::document_name`] with a `*` while there are unsaved changes.
+    pub fn document_label(&self) -> String {
+        let dirty = if self.is_dirty() { "*" } else { "" };
+        format!("{}{dirty}", self.document_name())
+    }
+
+    /// Window title, carrying the dirty indicator from [`DemoApp::document_label`].
+    pub fn title(&self) -> String {
+        self.locale
+            .format("app.window_title", &[("document", &self.document_label())])
+    }
+
+    /// Run `action`, first asking to save if the document has unsaved changes.
+    pub fn request_action(&mut self, ctx: &egui::Context, action: PendingAction) {
+        if self.is_dirty() {
+            self.pending_action = Some(action);
+        } else {
+            self.perform_action(ctx, action);
+        }
+    }
+
+    fn perform_action(&mut self, ctx: &egui::Context, action: PendingAction) {
+        match action {
+            PendingAction::New => self.new_document(),
+            PendingAction::Open => self.open_document(),
+            PendingAction::Close => {
+                self.close_confirmed = true;
+                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            }
+        }
+    }
+
+    fn resolve_pending_action(&mut self, ctx: &egui::Context, choice: SaveChoice) {
+        let Some(action) = self.pending_action.take() else {
+            return;
+        };
+        match choice {
+            SaveChoice::Save => {
+                self.save_document();
+                // Only continue if the save went through; a cancelled picker or
+                // failed write keeps the document open.
+                if !self.is_dirty() {
+                    self.perform_action(ctx, action);
                 }
             }
-            ScalingMode::Style => {
-        // Style-driven discrete scaling (typography + spacing), leave zoom at 1.0
-        let style_scale = if win_width < 600.0 { 0.95 } else if win_width < 900.0 { 1.15 } else if win_width < 1280.0 { 1.35 } else { 1.60 };
-                // Apply only if meaningfully changed
-                let eps = 0.01;
-                // Track zoom_factor as the effective visual scale for labeling
-                if (self.zoom_factor - style_scale).abs() > eps {
-                    self.zoom_factor = style_scale;
-                    // Build from baseline style so scaling is idempotent
-                    let mut style = self.base_style.clone().unwrap_or_else(|| (*ctx.style()).clone());
-                    let base = style.clone();
-                    // Scale common spacings from base
-                    style.spacing.item_spacing = base.spacing.item_spacing * style_scale;
-                    style.spacing.button_padding = base.spacing.button_padding * style_scale;
-                    style.spacing.indent = base.spacing.indent * style_scale;
-                    style.spacing.interact_size = base.spacing.interact_size * style_scale;
-                    // Scale text styles from base
-                    for (ts, font_id) in style.text_styles.iter_mut() {
-                        if let Some(base_font) = base.text_styles.get(ts) {
-                            font_id.size = base_font.size * style_scale;
-                        } else {
-                            font_id.size *= style_scale;
+            SaveChoice::Discard => {
+                self.discarded_on_close = action == PendingAction::Close;
+                self.perform_action(ctx, action);
+            }
+            SaveChoice::Cancel => {}
+        }
+    }
+
+    /// Apply `edit` and record it for undo.
+    pub fn execute(&mut self, edit: Edit) {
+        edit.apply(self);
+        self.history.push(edit);
+    }
+
+    /// Revert the most recent step. Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.history.take_undo() else {
+            return false;
+        };
+        edit.revert(self);
+        true
+    }
+
+    /// Re-apply the most recently undone step. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.history.take_redo() else {
+            return false;
+        };
+        edit.apply(self);
+        true
+    }
+
+    /// Set the user zoom multiplier, clamped to [`USER_ZOOM_RANGE`].
+    pub fn set_user_zoom(&mut self, zoom: f32) {
+        let zoom = if zoom.is_finite() { zoom } else { 1.0 };
+        // Round so repeated steps don't drift away from whole percentages
+        self.user_zoom =
+            (zoom.clamp(*USER_ZOOM_RANGE.start(), *USER_ZOOM_RANGE.end()) * 100.0).round() / 100.0;
+    }
+
+    /// Apply the theme file at `path` on top of [`DemoApp::theme`] and reload it
+    /// whenever it changes on disk.
+    ///
+    /// On error the previous custom theme stays and the error is shown in the app;
+    /// the file is still watched, so fixing it applies it.
+    pub fn load_theme_file(&mut self, path: impl Into<PathBuf>) -> Result<(), ThemeError> {
+        let watch = ThemeFileWatch::new(path);
+        let result = self.read_theme_file(&watch.path);
+        self.theme_watch = Some(watch);
+        result
+    }
+
+    /// Stop using and watching the custom theme file.
+    pub fn clear_custom_theme(&mut self) {
+        self.custom_theme = None;
+        self.theme_error = None;
+        self.theme_watch = None;
+    }
+
+    fn read_theme_file(&mut self, path: &Path) -> Result<(), ThemeError> {
+        match ThemeFile::load(path) {
+            Ok(theme) => {
+                self.custom_theme = Some(theme);
+                self.theme_error = None;
+                Ok(())
+            }
+            Err(err) => {
+                self.theme_error = Some(self.locale.format(
+                    "error.theme_file",
+                    &[
+                        ("path", &path.display()),
+                        ("error", &err.message_in(self.locale)),
+                    ],
+                ));
+                Err(err)
+            }
+        }
+    }
+
+    fn poll_theme_file(&mut self, ctx: &egui::Context) {
+        let Some(watch) = &mut self.theme_watch else {
+            return;
+        };
+        if watch.poll(ctx.input(|i| i.time)) {
+            let path = watch.path.clone();
+            // A broken edit is reported through `theme_error`
+            let _ = self.read_theme_file(&path);
+        }
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(THEME_POLL_INTERVAL));
+    }
+
+    /// Inline errors for the Name/Age form under [`DemoApp::validator`], in [`DemoApp::locale`].
+    pub fn form_errors(&self) -> FormErrors {
+        self.validator
+            .validate_in(self.locale, &self.name, self.age)
+    }
+
+    pub fn is_form_valid(&self) -> bool {
+        self.form_errors().is_valid()
+    }
+
+    /// Replace the card set with the cards from `source`.
+    ///
+    /// Card sets that repeat an id are rejected. On error the current cards are kept.
+    pub fn load_cards(&mut self, source: &mut dyn CardSource) -> Result<(), CardSourceError> {
+        let cards = source.load()?;
+        if let Some(id) = Card::duplicate_id(&cards) {
+            return Err(CardSourceError::DuplicateId(id));
+        }
+        self.set_cards(cards);
+        self.open_card_details.clear();
+        self.history.clear();
+        Ok(())
+    }
+
+    /// Load the startup cards file as the unmodified document.
+    ///
+    /// A file that can't be loaded is reported in the status area, and the current
+    /// cards are kept.
+    pub fn load_cards_file(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        match self.load_cards(&mut FileCardSource::new(&path)) {
+            Ok(()) => self.mark_clean(),
+            Err(err) => {
+                self.file_error = Some(self.locale.format(
+                    "error.load_cards",
+                    &[
+                        ("path", &path.display()),
+                        ("error", &err.message_in(self.locale)),
+                    ],
+                ));
+            }
+        }
+    }
+
+    /// Run [`DemoApp::card_command`] for the card with `id`.
+    pub fn run_card_command(&mut self, id: u64) {
+        if let Some(card) = self.cards.iter().find(|card| card.id == id) {
+            self.card_status = Some(self.card_command.run(card, self.locale));
+        }
+    }
+
+    /// Insert a copy of the card with `id` right after it, under a fresh id.
+    ///
+    /// Returns the applied [`Edit`], or `None` if there is no such card.
+    pub fn duplicate_card(&mut self, id: u64) -> Option<Edit> {
+        let index = self.cards.iter().position(|card| card.id == id)?;
+        let next_id = self.cards.iter().map(|card| card.id).max().unwrap_or(0) + 1;
+        let original = &self.cards[index];
+        let copy = Card {
+            id: next_id,
+            title: self
+                .locale
+                .format("card.copy_title", &[("title", &original.title)]),
+            pinned: false,
+            ..original.clone()
+        };
+        let edit = Edit::Insert {
+            index: index + 1,
+            card: copy,
+        };
+        edit.apply(self);
+        Some(edit)
+    }
+
+    /// Returns the applied [`Edit`], or `None` if there is no such card.
+    pub fn delete_card(&mut self, id: u64) -> Option<Edit> {
+        let index = self.cards.iter().position(|card| card.id == id)?;
+        let edit = Edit::Remove {
+            index,
+            card: self.cards[index].clone(),
+        };
+        edit.apply(self);
+        self.open_card_details.remove(&id);
+        Some(edit)
+    }
+
+    /// Returns the applied [`Edit`], or `None` if there is no such card.
+    pub fn toggle_pin(&mut self, id: u64) -> Option<Edit> {
+        self.cards.iter().find(|card| card.id == id)?;
+        let edit = Edit::TogglePin { id };
+        edit.apply(self);
+        Some(edit)
+    }
+
+    fn apply_card_event(&mut self, event: CardEvent) {
+        let edit = match event {
+            CardEvent::Action(id) => {
+                self.run_card_command(id);
+                None
+            }
+            CardEvent::Details(id) => {
+                self.open_card_details.insert(id);
+                None
+            }
+            CardEvent::Duplicate(id) => self.duplicate_card(id),
+            CardEvent::Delete(id) => self.delete_card(id),
+            CardEvent::TogglePin(id) => self.toggle_pin(id),
+        };
+        if let Some(edit) = edit {
+            self.history.push(edit);
+        }
+    }
+
+    /// File → New: reset the document.
+    pub fn new_document(&mut self) {
+        Document::default().apply_to(self);
+        self.open_card_details.clear();
+        self.history.clear();
+        self.touched = TouchedFields::default();
+        self.document_path = None;
+        self.file_error = None;
+        self.mark_clean();
+    }
+
+    /// File → Open: replace the document with one chosen by [`DemoApp::file_picker`].
+    pub fn open_document(&mut self) {
+        let Some(path) = self.file_picker.pick_open() else {
+            return;
+        };
+        match Document::read(&path) {
+            Ok(document) => {
+                document.apply_to(self);
+                self.open_card_details.clear();
+                self.history.clear();
+                self.touched = TouchedFields::default();
+                self.document_path = Some(path);
+                self.file_error = None;
+                self.mark_clean();
+            }
+            Err(err) => {
+                self.file_error = Some(self.locale.format(
+                    "error.open",
+                    &[
+                        ("path", &path.display()),
+                        ("error", &err.message_in(self.locale)),
+                    ],
+                ));
+            }
+        }
+    }
+
+    /// File → Save: write to the current path, asking the picker for one if there is none.
+    ///
+    /// Refuses while the form has validation errors.
+    pub fn save_document(&mut self) {
+        if !self.is_form_valid() {
+            self.touched = TouchedFields::ALL;
+            self.file_error = Some(self.locale.text("error.form_invalid").to_owned());
+            return;
+        }
+        let Some(path) = self.file_picker.pick_save(self.document_path.as_deref()) else {
+            return;
+        };
+        match Document::from_app(self).write(&path) {
+            Ok(()) => {
+                self.document_path = Some(path);
+                self.file_error = None;
+                self.mark_clean();
+            }
+            Err(err) => {
+                self.file_error = Some(self.locale.format(
+                    "error.save",
+                    &[
+                        ("path", &path.display()),
+                        ("error", &err.message_in(self.locale)),
+                    ],
+                ));
+            }
+        }
+    }
+}
+
+impl DemoApp {
+    fn run_shortcut(&mut self, ctx: &egui::Context, action: ShortcutAction) {
+        match action {
+            ShortcutAction::Undo => {
+                self.undo();
+            }
+            ShortcutAction::Redo => {
+                self.redo();
+            }
+            ShortcutAction::Increment => self.execute(Edit::Counter {
+                before: self.counter,
+                after: self.counter + 1,
+            }),
+            ShortcutAction::Decrement => self.execute(Edit::Counter {
+                before: self.counter,
+                after: self.counter - 1,
+            }),
+            ShortcutAction::ShowDialog => self.show_confirmation_dialog = true,
+            ShortcutAction::ToggleScaling => {
+                let after = match self.scaling_mode {
+                    ScalingMode::Zoom => ScalingMode::Style,
+                    ScalingMode::Style => ScalingMode::Fluid,
+                    ScalingMode::Fluid => ScalingMode::Zoom,
+                };
+                self.execute(Edit::ScalingMode {
+                    before: self.scaling_mode,
+                    after,
+                });
+            }
+            ShortcutAction::ZoomIn => self.set_user_zoom(self.user_zoom + USER_ZOOM_STEP),
+            ShortcutAction::ZoomOut => self.set_user_zoom(self.user_zoom - USER_ZOOM_STEP),
+            ShortcutAction::ResetZoom => self.set_user_zoom(1.0),
+            ShortcutAction::FocusSearch => {
+                ctx.memory_mut(|mem| mem.request_focus(egui::Id::new(CARD_SEARCH_ID)));
+            }
+        }
+    }
+
+    /// Help → Keyboard Shortcuts: every binding with its platform key label.
+    fn show_shortcuts_window(&mut self, ctx: &egui::Context) {
+        let locale = self.locale;
+        let mut open = self.show_shortcuts;
+        egui::Window::new(locale.text("menu.keyboard_shortcuts"))
+            .id(egui::Id::new("keyboard_shortcuts"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("shortcut_list")
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        // One row per action, listing every combo bound to it
+                        let mut rows: Vec<(ShortcutAction, Vec<String>)> = Vec::new();
+                        for (shortcut, action) in self.shortcuts.bindings() {
+                            let text = ctx.format_shortcut(shortcut);
+                            match rows.iter_mut().find(|(bound, _)| bound == action) {
+                                Some((_, texts)) => texts.push(text),
+                                None => rows.push((*action, vec![text])),
+                            }
                         }
-                    }
-                    ctx.set_style(style);
-                    // Ensure base zoom (pixels_per_point multiplier) is neutral in this mode
-                    ctx.set_zoom_factor(1.0);
+                        for (action, texts) in rows {
+                            ui.label(locale.text(action.key()));
+                            let combos = texts.into_iter().reduce(|first, second| {
+                                locale.format(
+                                    "shortcuts.either",
+                                    &[("first", &first), ("second", &second)],
+                                )
+                            });
+                            ui.label(combos.unwrap_or_default());
+                            ui.end_row();
+                        }
+                    });
+            });
+        self.show_shortcuts = open;
+    }
+
+    fn show_card_details(&mut self, ctx: &egui::Context) {
+        let locale = self.locale;
+        let mut closed = Vec::new();
+        for &id in &self.open_card_details {
+            let Some(card) = self.cards.iter().find(|card| card.id == id) else {
+                closed.push(id);
+                continue;
+            };
+            let mut open = true;
+            egui::Window::new(locale.format("details.window", &[("title", &card.title)]))
+                .id(egui::Id::new(("card_details", id)))
+                .open(&mut open)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    let detail = |key, value: &dyn std::fmt::Display| {
+                        locale.format(key, &[("value", value)])
+                    };
+                    ui.label(detail("details.title", &card.title));
+                    ui.label(detail("details.body", &card.body));
+                    let tags = if card.tags.is_empty() {
+                        locale.text("details.no_tags").to_owned()
+                    } else {
+                        card.tags.join(", ")
+                    };
+                    ui.label(detail("details.tags", &tags));
+                    ui.label(detail("details.created", &card.created));
+                    let pinned = if card.pinned {
+                        "common.yes"
+                    } else {
+                        "common.no"
+                    };
+                    ui.label(detail("details.pinned", &locale.text(pinned)));
+                    ui.label(detail("details.id", &card.id));
+                });
+            if !open {
+                closed.push(id);
+            }
+        }
+        for id in closed {
+            self.open_card_details.remove(&id);
+        }
+    }
+}
+
+fn render_card(
+    ui: &mut egui::Ui,
+    locale: Locale,
+    direction: LayoutDirection,
+    card: &Card,
+    title_shared: bool,
+    events: &mut Vec<CardEvent>,
+) {
+    ui.with_layout(column_layout(direction), |ui| {
+        ui.heading(&card.title);
+        if card.pinned {
+            ui.small(locale.text("card.pinned"));
+        }
+        ui.label(&card.body);
+        ui.horizontal_wrapped(|ui| {
+            let action = ui.small_button(locale.text("card.action"));
+            label_for_card(ui, locale, &action, "card.action", card, title_shared);
+            if action.clicked() {
+                events.push(CardEvent::Action(card.id));
+            }
+
+            let (more, _) = egui::containers::menu::MenuButton::from_button(
+                egui::Button::new(locale.text("card.more")).small(),
+            )
+            .ui(ui, |ui| {
+                if ui.button(locale.text("card.duplicate")).clicked() {
+                    events.push(CardEvent::Duplicate(card.id));
+                }
+                if ui.button(locale.text("card.delete")).clicked() {
+                    events.push(CardEvent::Delete(card.id));
                 }
+                let pin = if card.pinned {
+                    "card.unpin"
+                } else {
+                    "card.pin"
+                };
+                if ui.button(locale.text(pin)).clicked() {
+                    events.push(CardEvent::TogglePin(card.id));
+                }
+            });
+            label_for_card(ui, locale, &more, "card.more", card, title_shared);
+
+            let details = ui.small_button(locale.text("card.details"));
+            label_for_card(ui, locale, &details, "card.details", card, title_shared);
+            if details.clicked() {
+                events.push(CardEvent::Details(card.id));
             }
+        });
+    });
+}
+
+// Top-down layout whose `horizontal` rows run in `direction`; egui flips rows and
+// right-aligns text when a vertical layout is aligned to the right
+fn column_layout(direction: LayoutDirection) -> egui::Layout {
+    match direction {
+        LayoutDirection::LeftToRight => egui::Layout::top_down(egui::Align::Min),
+        LayoutDirection::RightToLeft => egui::Layout::top_down(egui::Align::Max),
+    }
+}
+
+// A label followed by the field it names; the field is `labelled_by` the label, so
+// screen readers (and `get_by_label`) find the input under the label's text
+fn labelled_field(
+    ui: &mut egui::Ui,
+    label: &str,
+    add_field: impl FnOnce(&mut egui::Ui) -> egui::Response,
+) -> egui::Response {
+    let label = ui.label(label);
+    add_field(ui).labelled_by(label.id)
+}
+
+// Menu entry showing the key combo bound to `action`, if any
+fn menu_item<'a>(
+    ctx: &egui::Context,
+    shortcuts: &ShortcutRegistry,
+    text: &'a str,
+    action: ShortcutAction,
+) -> egui::Button<'a> {
+    let button = egui::Button::new(text);
+    match shortcuts.shortcut_for(action) {
+        Some(shortcut) => button.shortcut_text(ctx.format_shortcut(&shortcut)),
+        None => button,
+    }
+}
+
+// Every card repeats the same buttons, so name the card in the accessible label,
+// e.g. "Details for Card 3"; a title other cards share gets the card id as well
+fn label_for_card(
+    ui: &egui::Ui,
+    locale: Locale,
+    response: &egui::Response,
+    control: &str,
+    card: &Card,
+    title_shared: bool,
+) {
+    let key = if title_shared {
+        "card.control_label_id"
+    } else {
+        "card.control_label"
+    };
+    let label = locale.format(
+        key,
+        &[
+            ("control", &locale.text(control)),
+            ("title", &card.title),
+            ("id", &card.id),
+        ],
+    );
+    ui.ctx().accesskit_node_builder(response.id, |node| {
+        node.set_label(label);
+    });
+}
+
+impl eframe::App for DemoApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        Preferences::from_app(self).save(storage);
+    }
+
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // The user zoom shortcuts replace egui's own Ctrl+Plus/Minus/0 handling,
+        // which would otherwise fight the adaptive zoom
+        if ctx.options(|o| o.zoom_with_keyboard) {
+            ctx.options_mut(|o| o.zoom_with_keyboard = false);
         }
 
+        // Hold a window close until unsaved changes are saved or discarded
+        if ctx.input(|i| i.viewport().close_requested()) && !self.close_confirmed && self.is_dirty()
+        {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            self.pending_action = Some(PendingAction::Close);
+        }
+
+        // Shortcuts are consumed before any widget runs, so app-level undo wins over
+        // the text field's own. Dialogs block them like they block clicks.
+        if !self.show_confirmation_dialog
+            && self.pending_action.is_none()
+            && let Some(action) = ctx.input_mut(|i| self.shortcuts.consume(i))
+        {
+            self.run_shortcut(ctx, action);
+        }
+
+        // Measure unscaled window width (in points) and base pixels-per-point once per frame
+        // Use physical width (points * ppp) for stable, DPI-independent breakpoints
+        let unscaled_points = ctx.available_rect().width();
+        let base_ppp = ctx.pixels_per_point();
+        // Treat pixels_per_point as a stable proxy for physical scaling captured before zoom changes
+        let window_px = unscaled_points * base_ppp;
+        let layout = LayoutDecision::compute_with_previous(
+            &self.breakpoints,
+            window_px,
+            base_ppp,
+            self.left_panel_width_px,
+            self.layout.as_ref(),
+        );
+        if self.layout.is_some_and(|previous| previous != layout) {
+            self.layout_transitions += 1;
+        }
+        self.layout = Some(layout);
+        // Adaptive scaling based on the window size class (or width, when fluid),
+        // times the user's own zoom
+        let adaptive = if self.scaling_mode == ScalingMode::Fluid {
+            let scale = self.breakpoints.fluid.scale(window_px, self.fluid_scale);
+            self.fluid_scale = Some(scale);
+            scale
+        } else {
+            self.fluid_scale = None;
+            layout.scale(self.scaling_mode)
+        };
+        let desired = adaptive * self.user_zoom;
+        // Zoom-based modes scale through pixels_per_point; Style mode keeps zoom at
+        // 1.0 and scales the style instead
+        let (zoom, style_scale) = match self.scaling_mode {
+            ScalingMode::Zoom | ScalingMode::Fluid => (desired, 1.0),
+            ScalingMode::Style => (1.0, desired),
+        };
+        // The theme and custom theme file make up the base style that gets scaled
+        self.poll_theme_file(ctx);
+        let preference = self.theme.preference();
+        if ctx.options(|o| o.theme_preference) != preference {
+            ctx.set_theme(preference);
+        }
+        let resolved = ctx.theme();
+        let theme_changed =
+            self.applied_theme
+                .as_ref()
+                .is_none_or(|(theme, dark_light, custom)| {
+                    (*theme, *dark_light) != (self.theme, resolved) || *custom != self.custom_theme
+                });
+        if theme_changed {
+            let mut style = self
+                .unthemed_style
+                .get_or_insert_with(|| (*ctx.style()).clone())
+                .clone();
+            style.visuals = self.theme.visuals(resolved);
+            if let Some(custom) = &self.custom_theme {
+                custom.apply(&mut style);
+            }
+            self.style_scaler.set_base(style);
+            self.applied_theme = Some((self.theme, resolved, self.custom_theme.clone()));
+        }
+        self.style_scaler.apply(ctx, style_scale);
+        if (ctx.zoom_factor() - zoom).abs() > 0.001 {
+            ctx.set_zoom_factor(zoom);
+        }
+        // Effective visual scale, shown as `Scale: …%`
+        self.zoom_factor = desired;
+
         // Top menu bar for navigation and accessibility
+        let locale = self.locale;
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::MenuBar::new().ui(ui, |ui| {
-                ui.menu_button("File", |ui| {
-                    let _ = ui.button("New");
-                    let _ = ui.button("Open");
-                    let _ = ui.button("Save");
+                ui.menu_button(locale.text("menu.file"), |ui| {
+                    if ui.button(locale.text("menu.new")).clicked() {
+                        self.request_action(ctx, PendingAction::New);
+                    }
+                    if ui.button(locale.text("menu.open")).clicked() {
+                        self.request_action(ctx, PendingAction::Open);
+                    }
+                    if ui
+                        .add_enabled(
+                            self.is_form_valid(),
+                            egui::Button::new(locale.text("menu.save")),
+                        )
+                        .clicked()
+                    {
+                        self.save_document();
+                    }
                 });
-                ui.menu_button("View", |ui| {
-                    ui.label("Layout is responsive to window width");
+                ui.menu_button(locale.text("menu.edit"), |ui| {
+                    let undo = menu_item(
+                        ctx,
+                        &self.shortcuts,
+                        locale.text("menu.undo"),
+                        ShortcutAction::Undo,
+                    );
+                    if ui.add_enabled(self.history.can_undo(), undo).clicked() {
+                        self.undo();
+                    }
+                    let redo = menu_item(
+                        ctx,
+                        &self.shortcuts,
+                        locale.text("menu.redo"),
+                        ShortcutAction::Redo,
+                    );
+                    if ui.add_enabled(self.history.can_redo(), redo).clicked() {
+                        self.redo();
+                    }
+                });
+                ui.menu_button(locale.text("menu.view"), |ui| {
+                    ui.label(locale.text("menu.view_hint"));
                     ui.separator();
-                    ui.label("Scaling strategy");
+                    ui.label(locale.text("menu.scaling_strategy"));
                     let mut mode = self.scaling_mode;
-                    if ui.radio(mode == ScalingMode::Zoom, "Zoom-based").clicked() {
+                    if ui
+                        .radio(mode == ScalingMode::Zoom, locale.text("menu.scaling_zoom"))
+                        .clicked()
+                    {
                         mode = ScalingMode::Zoom;
                     }
-                    if ui.radio(mode == ScalingMode::Style, "Style-based").clicked() {
+                    if ui
+                        .radio(
+                            mode == ScalingMode::Style,
+                            locale.text("menu.scaling_style"),
+                        )
+                        .clicked()
+                    {
                         mode = ScalingMode::Style;
                     }
+                    if ui
+                        .radio(
+                            mode == ScalingMode::Fluid,
+                            locale.text("menu.scaling_fluid"),
+                        )
+                        .clicked()
+                    {
+                        mode = ScalingMode::Fluid;
+                    }
                     if mode != self.scaling_mode {
-                        self.scaling_mode = mode;
-                        // Reset to force re-application next frame
-                        self.zoom_factor = 0.0;
+                        self.execute(Edit::ScalingMode {
+                            before: self.scaling_mode,
+                            after: mode,
+                        });
                     }
-                    ui.label(format!("Scaling mode: {}",
-                        match self.scaling_mode { ScalingMode::Zoom => "Zoom", ScalingMode::Style => "Style" }
+                    ui.label(locale.format(
+                        "status.scaling_mode",
+                        &[("mode", &locale.text(self.scaling_mode.key()))],
                     ));
+                    ui.separator();
+                    ui.label(locale.text("menu.theme"));
+                    let mut theme = self.theme;
+                    for choice in Theme::ALL {
+                        ui.radio_value(&mut theme, choice, locale.text(choice.key()));
+                    }
+                    if theme != self.theme {
+                        self.execute(Edit::Theme {
+                            before: self.theme,
+                            after: theme,
+                        });
+                    }
+                    if self.theme_watch.is_some()
+                        && ui.button(locale.text("menu.clear_custom_theme")).clicked()
+                    {
+                        self.clear_custom_theme();
+                    }
+                    ui.separator();
+                    let mut zoom = self.user_zoom;
+                    let slider = egui::Slider::new(&mut zoom, USER_ZOOM_RANGE)
+                        .step_by(USER_ZOOM_STEP.into())
+                        .text(locale.text("menu.zoom"));
+                    if ui.add(slider).changed() {
+                        self.set_user_zoom(zoom);
+                    }
+                    let reset = menu_item(
+                        ctx,
+                        &self.shortcuts,
+                        locale.text("menu.reset_zoom"),
+                        ShortcutAction::ResetZoom,
+                    );
+                    if ui.add_enabled(self.user_zoom != 1.0, reset).clicked() {
+                        self.set_user_zoom(1.0);
+                    }
+                    ui.separator();
+                    ui.label(locale.text("menu.layout_direction"));
+                    for choice in LayoutDirection::ALL {
+                        ui.radio_value(
+                            &mut self.layout_direction,
+                            choice,
+                            locale.text(choice.key()),
+                        );
+                    }
+                    ui.separator();
+                    ui.label(locale.text("menu.language"));
+                    for choice in Locale::ALL {
+                        ui.radio_value(&mut self.locale, choice, choice.native_name());
+                    }
                 });
-                ui.menu_button("Help", |ui| {
-                    ui.label("Demo showing responsive layouts");
+                ui.menu_button(locale.text("menu.help"), |ui| {
+                    ui.label(locale.text("menu.help_hint"));
+                    if ui.button(locale.text("menu.keyboard_shortcuts")).clicked() {
+                        self.show_shortcuts = true;
+                    }
                 });
             });
         });
 
-    // Determine if we should stack content
-    // Criteria:
-    // - Always stack if the overall physical width is below 600 px (stable vs zoom/DPI)
-    // - Also stack if the predicted central (right) area becomes too narrow in logical points
-    //   due to a wide left panel. We compare central width in points so expectations at
-    //   specific window sizes (e.g., 820 pt) remain stable across pixels-per-point.
-    let stack_breakpoint = 600.0_f32; // overall window threshold (physical px)
-    let central_min_breakpoint_points = 460.0_f32; // minimum central width (in points) before stacking
-    let logical_width = window_px; // physical px for overall decisions
-    // Estimate previous left panel width in points (convert stored px back to points).
-    let default_left_points = 280.0; // default guess for left panel width (points)
-    let predicted_left_points = self
-        .left_panel_width_px
-        .map(|px| px / base_ppp)
-        .unwrap_or(default_left_points);
-    let predicted_central_points = (unscaled_points - predicted_left_points).max(0.0);
-    let is_stacked = logical_width < stack_breakpoint
-        || predicted_central_points < central_min_breakpoint_points;
-
         // Shared closures to render filters and main content to avoid duplication
-        let render_filters = |ui: &mut egui::Ui| {
-            ui.heading("Filters");
+        let render_filters = |ui: &mut egui::Ui, this: &mut DemoApp| {
+            let locale = this.locale;
+            ui.heading(locale.text("filters.heading"));
             ui.separator();
-            ui.checkbox(&mut false, "Placeholder filter A");
-            ui.checkbox(&mut false, "Placeholder filter B");
+            // Fixed id so the search box keeps focus and state across layout switches
+            let search = ui.add(
+                egui::TextEdit::singleline(&mut this.filters.search)
+                    .id(egui::Id::new(CARD_SEARCH_ID))
+                    .hint_text(locale.text("filters.search")),
+            );
+            ui.ctx().accesskit_node_builder(search.id, |node| {
+                node.set_role(egui::accesskit::Role::SearchInput);
+                node.set_label(locale.text("filters.search"));
+            });
+            ui.label(locale.text("filters.tags"));
+            this.filtered_cards
+                .update(this.cards_revision, &this.cards, &this.filters);
+            for tag in &this.filtered_cards.tags {
+                let mut selected = this.filters.tags.contains(tag);
+                if ui.checkbox(&mut selected, tag).changed() {
+                    this.filters.toggle_tag(tag);
+                }
+            }
+            egui::ComboBox::new("sort_order", locale.text("filters.sort"))
+                .selected_text(locale.text(this.filters.sort.key()))
+                .show_ui(ui, |ui| {
+                    for order in SortOrder::ALL {
+                        ui.selectable_value(
+                            &mut this.filters.sort,
+                            order,
+                            locale.text(order.key()),
+                        );
+                    }
+                });
+            if this.filters != FilterState::default()
+                && ui.button(locale.text("filters.clear")).clicked()
+            {
+                this.filters = FilterState::default();
+            }
             ui.separator();
-            ui.label("Use the central area to interact with the app");
+            ui.label(locale.text("filters.hint"));
         };
 
-        let render_main_content =
-            |ui: &mut egui::Ui, this: &mut DemoApp, ctx: &egui::Context, stacked: bool| {
-                ui.heading("egui_kittest Demo App");
-                ui.label(format!(
-                    "Layout: {}",
-                    if stacked { "Stacked" } else { "Side+Central" }
-                ));
-                // Expose semantic scale indicators for tests and a11y
-                let scale_pct = (this.zoom_factor * 100.0).round() as i32;
-                ui.label(format!("Scale: {scale_pct}%"));
-                ui.label(format!(
-                    "Scaling mode: {}",
-                    match this.scaling_mode {
-                        ScalingMode::Zoom => "Zoom",
-                        ScalingMode::Style => "Style",
-                    }
+        let render_main_content = |ui: &mut egui::Ui, this: &mut DemoApp, ctx: &egui::Context| {
+            let locale = this.locale;
+            ui.heading(locale.text("app.title"));
+            ui.label(locale.format(
+                "status.layout",
+                &[("mode", &locale.text(layout.mode.key()))],
+            ));
+            // Expose semantic scale indicators for tests and a11y
+            let scale_pct = (this.zoom_factor * 100.0).round() as i32;
+            if this.user_zoom == 1.0 {
+                ui.label(locale.format("status.scale", &[("percent", &scale_pct)]));
+            } else {
+                let user_pct = (this.user_zoom * 100.0).round() as i32;
+                ui.label(locale.format(
+                    "status.scale_user_zoom",
+                    &[("percent", &scale_pct), ("user", &user_pct)],
                 ));
-                let bucket = if logical_width >= 900.0 {
-                    "Large"
-                } else if logical_width >= 600.0 {
-                    "Medium"
-                } else {
-                    "Small"
-                };
-                ui.label(format!("Scale bucket: {bucket}"));
-
-                // Form area – stacks on small widths
-                let available_width = ui.available_width();
-                let is_narrow = available_width < 500.0;
-                if is_narrow {
-                    ui.vertical(|ui| {
-                        ui.horizontal(|ui| {
-                            ui.label("Name:");
-                            ui.text_edit_singleline(&mut this.name);
-                        });
-                        ui.horizontal(|ui| {
-                            ui.label("Age:");
-                            ui.add(egui::DragValue::new(&mut this.age).range(0..=120));
-                        });
+            }
+            ui.label(locale.format(
+                "status.scaling_mode",
+                &[("mode", &locale.text(this.scaling_mode.key()))],
+            ));
+            ui.label(locale.format(
+                "status.scale_bucket",
+                &[("bucket", &locale.text(layout.bucket.key()))],
+            ));
+            ui.label(locale.format("status.theme", &[("theme", &locale.text(this.theme.key()))]));
+            if let Some(custom) = &this.custom_theme {
+                ui.label(locale.format("status.custom_theme", &[("name", &custom.name)]));
+            }
+            if let Some(err) = &this.theme_error {
+                ui.colored_label(ui.visuals().error_fg_color, err);
+            }
+            ui.label(locale.format("status.document", &[("name", &this.document_label())]));
+            if let Some(err) = &this.file_error {
+                ui.colored_label(ui.visuals().error_fg_color, err);
+            }
+
+            // Form area – stacks on small widths
+            let available_width = ui.available_width();
+            let is_narrow = available_width < 500.0;
+            let (name_before, age_before) = (this.name.clone(), this.age);
+            let (name_field, age_field) = if is_narrow {
+                ui.with_layout(column_layout(this.layout_direction), |ui| {
+                    let name = ui
+                        .horizontal(|ui| {
+                            labelled_field(ui, locale.text("form.name"), |ui| {
+                                ui.text_edit_singleline(&mut this.name)
+                            })
+                        })
+                        .inner;
+                    let age = ui
+                        .horizontal(|ui| {
+                            labelled_field(ui, locale.text("form.age"), |ui| {
+                                ui.add(egui::DragValue::new(&mut this.age).range(AGE_RANGE))
+                            })
+                        })
+                        .inner;
+                    (name, age)
+                })
+                .inner
+            } else {
+                ui.horizontal(|ui| {
+                    let name = labelled_field(ui, locale.text("form.name"), |ui| {
+                        ui.text_edit_singleline(&mut this.name)
                     });
-                } else {
-                    ui.horizontal(|ui| {
-                        ui.label("Name:");
-                        ui.text_edit_singleline(&mut this.name);
-                        ui.separator();
-                        ui.label("Age:");
-                        ui.add(egui::DragValue::new(&mut this.age).range(0..=120));
+                    ui.separator();
+                    let age = labelled_field(ui, locale.text("form.age"), |ui| {
+                        ui.add(egui::DragValue::new(&mut this.age).range(AGE_RANGE))
                     });
-                }
-
-                ui.separator();
-
-                // Counter controls – buttons first for easy keyboard/screen reader focus
-                ui.horizontal_wrapped(|ui| {
-                    if ui.button("Increment").clicked() {
-                        this.counter += 1;
-                    }
-                    if ui.button("Decrement").clicked() {
-                        this.counter -= 1;
-                    }
-                    ui.label(format!("Counter: {}", this.counter));
+                    (name, age)
+                })
+                .inner
+            };
+            // Keystrokes and drags merge into one undo step until the field is left
+            if name_field.changed() {
+                this.history.push(Edit::Name {
+                    before: name_before,
+                    after: this.name.clone(),
                 });
+            }
+            if age_field.changed() {
+                this.history.push(Edit::Age {
+                    before: age_before,
+                    after: this.age,
+                });
+            }
+            if name_field.lost_focus() || age_field.lost_focus() || age_field.drag_stopped() {
+                this.history.seal();
+            }
+            this.touched.name |= name_field.changed() || name_field.lost_focus();
+            this.touched.age |= age_field.changed() || age_field.lost_focus();
+            // Validate after the edits so errors never lag a frame behind
+            let errors = this.form_errors();
+            let shown = this.touched.visible(errors.clone());
+            // A scope takes one auto id whatever it holds, so errors coming and going
+            // leave the ids (and keyboard focus) of the widgets below untouched
+            ui.scope(|ui| {
+                for (field, error) in [(&name_field, &shown.name), (&age_field, &shown.age)] {
+                    if let Some(error) = error {
+                        ui.ctx().accesskit_node_builder(field.id, |node| {
+                            node.set_description(error.clone());
+                        });
+                        ui.colored_label(ui.visuals().error_fg_color, error);
+                    }
+                }
+            });
 
-                ui.separator();
-                ui.label(format!(
-                    "Hello, {}! You are {} years old.",
-                    this.name, this.age
-                ));
-
-                ui.separator();
+            ui.separator();
 
-                // Dialog demo
-                if ui.button("Show Dialog").clicked() {
-                    this.show_confirmation_dialog = true;
+            // Counter controls – buttons first for easy keyboard/screen reader focus
+            ui.horizontal_wrapped(|ui| {
+                let before = this.counter;
+                if ui.button(locale.text("counter.increment")).clicked() {
+                    this.execute(Edit::Counter {
+                        before,
+                        after: before + 1,
+                    });
                 }
-                if this.show_confirmation_dialog {
-                    egui::Window::new("Confirmation")
-                        .collapsible(false)
-                        .show(ctx, |ui| {
-                            ui.label("Are you sure you want to continue?");
-                            ui.horizontal(|ui| {
-                                if ui.button("Yes").clicked() {
-                                    this.show_confirmation_dialog = false;
-                                }
-                                if ui.button("No").clicked() {
-                                    this.show_confirmation_dialog = false;
-                                }
-                            });
-                        });
+                if ui.button(locale.text("counter.decrement")).clicked() {
+                    this.execute(Edit::Counter {
+                        before,
+                        after: before - 1,
+                    });
                 }
+                ui.label(locale.format("counter.value", &[("count", &this.counter)]));
+            });
 
-                ui.separator();
+            // Greet only a valid form, never "Hello, !"
+            ui.scope(|ui| {
+                if errors.is_valid() {
+                    ui.separator();
+                    ui.label(locale.format_plural(
+                        "greeting",
+                        this.age.into(),
+                        &[("name", &this.name)],
+                    ));
+                }
+            });
+
+            ui.separator();
 
-                // Responsive card grid – adapts number of columns to central width (stable vs zoom)
-                let width = logical_width;
-                let cols = if width >= 900.0 {
-                    3
-                } else if width >= 600.0 {
-                    2
+            // Dialog demo
+            let show_dialog = ui.button(locale.text("dialog.show"));
+            if show_dialog.clicked() {
+                this.show_confirmation_dialog = true;
+            }
+            // Hand keyboard focus back once the modal layer no longer blocks this button
+            if this.refocus_show_dialog {
+                if ctx.memory(|mem| mem.top_modal_layer().is_none()) {
+                    show_dialog.request_focus();
+                    this.refocus_show_dialog = false;
                 } else {
-                    1
-                };
-                ui.label(format!("Columns: {cols}"));
-                let mut columns = vec![Vec::<usize>::new(); cols];
-                let cards = 6usize; // a few demo cards
-                for i in 0..cards {
-                    columns[i % cols].push(i);
+                    ctx.request_repaint();
                 }
-                ui.columns(cols, |uis| {
-                    for (col_idx, col_ui) in uis.iter_mut().enumerate() {
-                        for card_idx in &columns[col_idx] {
-                            egui::Frame::group(col_ui.style())
-                                .stroke(col_ui.visuals().widgets.noninteractive.bg_stroke)
-                                .show(col_ui, |ui| {
-                                    ui.vertical(|ui| {
-                                        ui.heading(format!("Card {}", card_idx + 1));
-                                        ui.label(
-                                        "This card wraps text and scales with the layout width.",
-                                    );
-                                        ui.horizontal_wrapped(|ui| {
-                                            let _ = ui.small_button("Action").clicked();
-                                            let _ = ui.small_button("More").clicked();
-                                            let _ = ui.small_button("Details").clicked();
-                                        });
-                                    });
-                                });
-                            col_ui.add_space(4.0);
-                        }
+            }
+            if let Some(outcome) = this.last_confirmation {
+                ui.label(locale.format(
+                    "dialog.last_choice",
+                    &[("choice", &locale.text(outcome.key()))],
+                ));
+            }
+            if this.show_confirmation_dialog {
+                let outcome = ConfirmationDialog::new(
+                    locale.text("dialog.confirm_title"),
+                    locale.text("dialog.confirm_message"),
+                )
+                .confirm_text(locale.text("common.yes"))
+                .cancel_text(locale.text("common.no"))
+                .on_outcome(|outcome| {
+                    this.last_confirmation = Some(outcome);
+                    if let Some(hook) = &mut this.on_confirmation {
+                        hook(outcome);
                     }
+                })
+                .show(ctx);
+                if outcome.is_some() {
+                    this.show_confirmation_dialog = false;
+                    this.refocus_show_dialog = true;
+                }
+            }
+
+            ui.separator();
+
+            // Responsive card grid – column count comes from the physical window width (stable vs zoom)
+            let cols = layout.columns;
+            ui.label(locale.format("cards.columns", &[("count", &cols)]));
+            this.filtered_cards
+                .update(this.cards_revision, &this.cards, &this.filters);
+            let shown = &this.filtered_cards.indices;
+            ui.label(locale.format_plural(
+                "cards.showing",
+                this.cards.len() as u64,
+                &[("shown", &shown.len())],
+            ));
+            if let Some(status) = &this.card_status {
+                ui.label(locale.format("cards.last_action", &[("status", status)]));
+            }
+            if this.cards.is_empty() {
+                ui.label(locale.text("cards.empty"));
+            } else if shown.is_empty() {
+                ui.label(locale.text("cards.no_match"));
+            }
+            // Only rows inside the scroll viewport are laid out
+            let mut events = Vec::new();
+            let shared_titles = &this.filtered_cards.shared_titles;
+            this.card_grid
+                .show(ui, &this.cards, shown, cols, |ui, card| {
+                    // Scope widget ids to the card so they follow it when the grid reorders
+                    ui.push_id(card.id, |ui| {
+                        egui::Frame::group(ui.style())
+                            .stroke(ui.visuals().widgets.noninteractive.bg_stroke)
+                            .show(ui, |ui| {
+                                render_card(
+                                    ui,
+                                    locale,
+                                    this.layout_direction,
+                                    card,
+                                    shared_titles.contains(&card.title),
+                                    &mut events,
+                                )
+                            });
+                    });
                 });
-            };
+            for event in events {
+                this.apply_card_event(event);
+            }
+        };
 
-        if is_stacked {
+        // Child uis inherit this, so rows, text and grid columns all follow the direction
+        let content_layout = column_layout(self.layout_direction);
+        if layout.is_stacked() {
             // Narrow: stack Filters above Main inside a scrollable CentralPanel
             egui::CentralPanel::default().show(ctx, |ui| {
-                egui::ScrollArea::vertical()
-                    .auto_shrink([false; 2])
-                    .show(ui, |ui| {
-                        egui::Frame::group(ui.style()).show(ui, render_filters);
-                        ui.add_space(6.0);
-                        egui::Frame::group(ui.style())
-                            .show(ui, |ui| render_main_content(ui, self, ctx, true));
-                    });
+                ui.with_layout(content_layout, |ui| {
+                    egui::ScrollArea::vertical()
+                        .auto_shrink([false; 2])
+                        .show(ui, |ui| {
+                            egui::Frame::group(ui.style()).show(ui, |ui| render_filters(ui, self));
+                            ui.add_space(6.0);
+                            egui::Frame::group(ui.style())
+                                .show(ui, |ui| render_main_content(ui, self, ctx));
+                        });
+                });
             });
         } else {
-            // Wide: show Filters in a resizable side panel and Main in the central area
-            let side = egui::SidePanel::left("left_filters")
+            // Wide: show Filters in a resizable side panel and Main in the central area.
+            // The panel sits on the leading side: left, or right when mirrored.
+            let side = if self.layout_direction.is_right_to_left() {
+                egui::panel::Side::Right
+            } else {
+                egui::panel::Side::Left
+            };
+            let panel = egui::SidePanel::new(side, "filters_panel")
                 .resizable(true)
                 .show(ctx, |ui| {
-                    egui::ScrollArea::vertical()
-                        .auto_shrink([false; 2])
-                        .show(ui, render_filters);
+                    ui.with_layout(content_layout, |ui| {
+                        egui::ScrollArea::vertical()
+                            .auto_shrink([false; 2])
+                            .show(ui, |ui| render_filters(ui, self));
+                    });
                 });
-            // Record actual left panel width (convert to physical px) for next-frame prediction
-            let left_points = side.response.rect.width();
-            self.left_panel_width_px = Some(left_points * base_ppp);
+            // Record actual panel width (convert to physical px) for next-frame prediction
+            let panel_points = panel.response.rect.width();
+            self.left_panel_width_px = Some(panel_points * base_ppp);
 
             egui::CentralPanel::default().show(ctx, |ui| {
-                egui::ScrollArea::vertical()
-                    .auto_shrink([false; 2])
-                    .show(ui, |ui| render_main_content(ui, self, ctx, false));
+                ui.with_layout(content_layout, |ui| {
+                    egui::ScrollArea::vertical()
+                        .auto_shrink([false; 2])
+                        .show(ui, |ui| render_main_content(ui, self, ctx));
+                });
             });
         }
+
+        self.show_card_details(ctx);
+        self.show_shortcuts_window(ctx);
+
+        if self.pending_action.is_some() {
+            let document = self.document_name();
+            if let Some(choice) = SaveChangesDialog::new(&document)
+                .save_enabled(self.is_form_valid())
+                .locale(self.locale)
+                .show(ctx)
+            {
+                self.resolve_pending_action(ctx, choice);
+            }
+        }
+
+        // Keep the window title in sync with the document name and dirty state
+        let title = self.title();
+        if title != self.window_title {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title.clone()));
+            self.window_title = title;
+        }
     }
 }
@@ -10,6 +10,18 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "egui_kittest Demo",
         options,
-        Box::new(|_cc| Ok(Box::new(DemoApp::default()))),
+        Box::new(|cc| {
+            let mut app = DemoApp::from_storage(cc.storage);
+            // Optional first argument: a RON file with the cards to show; errors show
+            // in the app, which then starts with the default cards
+            if let Some(path) = std::env::args_os().nth(1) {
+                app.load_cards_file(path);
+            }
+            // Optional custom theme, reloaded while the app runs; errors show in the app
+            if let Some(path) = std::env::var_os("DEMO_THEME") {
+                let _ = app.load_theme_file(path);
+            }
+            Ok(Box::new(app))
+        }),
     )
 }
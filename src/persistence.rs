@@ -0,0 +1,114 @@
+//! Preferences that survive restarts through [`eframe::Storage`].
+//!
+//! Data is stored as versioned RON under [`PREFERENCES_KEY`]. Missing, corrupt or
+//! newer-than-supported data is ignored so the app always starts, worst case with defaults.
+
+use serde::{Deserialize, Serialize};
+
+use crate::validation::AGE_RANGE;
+use crate::{DemoApp, LayoutDirection, Locale, ScalingMode, Theme};
+
+/// Storage key for [`Preferences`].
+pub const PREFERENCES_KEY: &str = "demo_app_preferences";
+
+/// Current on-disk version of [`Preferences`].
+pub const PREFERENCES_VERSION: u32 = 1;
+
+/// The part of [`DemoApp`] that is persisted between runs.
+///
+/// Besides the settings, the form and counter come back as they were left. If the
+/// user discarded their changes when closing, the content as last opened or saved
+/// is kept instead, so the discarded edits don't return on the next launch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub version: u32,
+    pub name: String,
+    pub age: u32,
+    pub counter: i32,
+    pub scaling_mode: ScalingMode,
+    pub user_zoom: f32,
+    pub theme: Theme,
+    pub locale: Locale,
+    pub layout_direction: LayoutDirection,
+    pub left_panel_width_px: Option<f32>,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            version: PREFERENCES_VERSION,
+            name: String::new(),
+            age: 0,
+            counter: 0,
+            scaling_mode: ScalingMode::default(),
+            user_zoom: 1.0,
+            theme: Theme::default(),
+            locale: Locale::default(),
+            layout_direction: LayoutDirection::default(),
+            left_panel_width_px: None,
+        }
+    }
+}
+
+impl Preferences {
+    pub fn from_app(app: &DemoApp) -> Self {
+        let (name, age, counter) = if app.discarded_on_close {
+            let saved = &app.saved_document;
+            (saved.name.clone(), saved.age, saved.counter)
+        } else {
+            (app.name.clone(), app.age, app.counter)
+        };
+        Self {
+            version: PREFERENCES_VERSION,
+            name,
+            age,
+            counter,
+            scaling_mode: app.scaling_mode,
+            user_zoom: app.user_zoom,
+            theme: app.theme,
+            locale: app.locale,
+            layout_direction: app.layout_direction,
+            left_panel_width_px: app.left_panel_width_px,
+        }
+    }
+
+    /// Copy the preferences onto `app`, clamping values the UI could never produce.
+    pub fn apply_to(self, app: &mut DemoApp) {
+        app.name = self.name;
+        app.age = self.age.min(*AGE_RANGE.end());
+        app.counter = self.counter;
+        app.scaling_mode = self.scaling_mode;
+        app.set_user_zoom(self.user_zoom);
+        app.theme = self.theme;
+        app.locale = self.locale;
+        app.layout_direction = self.layout_direction;
+        app.left_panel_width_px = self
+            .left_panel_width_px
+            .filter(|px| px.is_finite() && *px > 0.0);
+    }
+
+    /// Read preferences from `storage`.
+    ///
+    /// Returns `None` if nothing is stored, the data can't be decoded, or it was
+    /// written by a newer version of the app.
+    pub fn load(storage: &dyn eframe::Storage) -> Option<Self> {
+        let prefs: Self = eframe::get_value(storage, PREFERENCES_KEY)?;
+        prefs.migrate()
+    }
+
+    pub fn save(&self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, PREFERENCES_KEY, self);
+    }
+
+    /// Bring older versions up to [`PREFERENCES_VERSION`].
+    ///
+    /// Fields missing from older data are filled in by `#[serde(default)]`.
+    fn migrate(mut self) -> Option<Self> {
+        if self.version > PREFERENCES_VERSION {
+            return None;
+        }
+        self.version = PREFERENCES_VERSION;
+        Some(self)
+    }
+}
@@ -0,0 +1,130 @@
+//! Style-based scaling: a base [`egui::Style`] with every size multiplied by a factor.
+//!
+//! [`StyleScaler`] owns the unscaled base style and caches one scaled copy per scale
+//! value, so switching between size classes doesn't rebuild the style each time. The
+//! cache is dropped whenever the base changes, e.g. when the theme is switched.
+
+use std::sync::Arc;
+
+use eframe::egui;
+
+/// Scaled styles kept before the oldest one is dropped.
+const MAX_CACHED_SCALES: usize = 8;
+
+/// `base` with all spacing, margins, widget sizes and text sizes multiplied by `scale`.
+pub fn scale_style(base: &egui::Style, scale: f32) -> egui::Style {
+    let mut style = base.clone();
+    for font_id in style.text_styles.values_mut() {
+        font_id.size *= scale;
+    }
+    if let Some(font_id) = &mut style.override_font_id {
+        font_id.size *= scale;
+    }
+
+    let spacing = &mut style.spacing;
+    spacing.item_spacing *= scale;
+    spacing.window_margin *= scale;
+    spacing.button_padding *= scale;
+    spacing.menu_margin *= scale;
+    spacing.indent *= scale;
+    spacing.interact_size *= scale;
+    spacing.slider_width *= scale;
+    spacing.slider_rail_height *= scale;
+    spacing.combo_width *= scale;
+    spacing.text_edit_width *= scale;
+    spacing.icon_width *= scale;
+    spacing.icon_width_inner *= scale;
+    spacing.icon_spacing *= scale;
+    spacing.default_area_size *= scale;
+    spacing.tooltip_width *= scale;
+    spacing.menu_width *= scale;
+    spacing.menu_spacing *= scale;
+    spacing.combo_height *= scale;
+
+    let scroll = &mut spacing.scroll;
+    scroll.bar_width *= scale;
+    scroll.handle_min_length *= scale;
+    scroll.bar_inner_margin *= scale;
+    scroll.bar_outer_margin *= scale;
+    scroll.floating_width *= scale;
+    scroll.floating_allocated_width *= scale;
+    style
+}
+
+/// Base style plus a cache of its scaled copies.
+#[derive(Clone, Debug, Default)]
+pub struct StyleScaler {
+    base: Option<Arc<egui::Style>>,
+    /// Scaled styles keyed by the bits of their scale, oldest first.
+    cache: Vec<(u32, Arc<egui::Style>)>,
+    /// The style last handed to the context, to notice changes made elsewhere.
+    applied: Option<Arc<egui::Style>>,
+}
+
+impl StyleScaler {
+    pub fn base(&self) -> Option<&egui::Style> {
+        self.base.as_deref()
+    }
+
+    /// Replace the base style. Cached copies are dropped if it differs from the old one.
+    ///
+    /// The next [`StyleScaler::apply`] uses this base as is, even if the context's
+    /// style was changed elsewhere in the meantime.
+    pub fn set_base(&mut self, style: egui::Style) {
+        if self.base.as_deref() != Some(&style) {
+            self.base = Some(Arc::new(style));
+            self.cache.clear();
+        }
+        self.applied = None;
+    }
+
+    /// Number of scaled styles currently cached.
+    pub fn cached(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// The base style scaled by `scale`, built on first use and cached after.
+    ///
+    /// Falls back to egui's default style until a base has been set.
+    pub fn scaled(&mut self, scale: f32) -> Arc<egui::Style> {
+        let base = self.base.get_or_insert_default().clone();
+        if scale == 1.0 {
+            return base;
+        }
+        let key = scale.to_bits();
+        if let Some((_, style)) = self.cache.iter().find(|(bits, _)| *bits == key) {
+            return style.clone();
+        }
+        if self.cache.len() == MAX_CACHED_SCALES {
+            self.cache.remove(0);
+        }
+        let style = Arc::new(scale_style(&base, scale));
+        self.cache.push((key, style.clone()));
+        style
+    }
+
+    /// Make `ctx` use the base style scaled by `scale`.
+    ///
+    /// The first call takes the context's style as the base. If the context's style was
+    /// replaced since the last call (say by a theme switch), its visuals are adopted
+    /// into the base; its sizes are not, as they may already be scaled.
+    pub fn apply(&mut self, ctx: &egui::Context, scale: f32) {
+        let current = ctx.style();
+        match (&self.base, &self.applied) {
+            (None, _) => self.set_base((*current).clone()),
+            (Some(base), Some(applied))
+                if !Arc::ptr_eq(applied, &current) && current.visuals != base.visuals =>
+            {
+                let mut style = (**base).clone();
+                style.visuals = current.visuals.clone();
+                self.set_base(style);
+            }
+            _ => {}
+        }
+        let style = self.scaled(scale);
+        if !Arc::ptr_eq(&current, &style) {
+            ctx.set_style(style.clone());
+        }
+        self.applied = Some(style);
+    }
+}
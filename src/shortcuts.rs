@@ -0,0 +1,178 @@
+//! Keyboard shortcuts and the app actions they trigger.
+//!
+//! [`ShortcutRegistry`] is the single place key combos are bound. It refuses a
+//! binding whose key combo is already taken, and it dispatches the most specific
+//! binding first so that Ctrl+Shift+Z is not also read as Ctrl+Z.
+
+use std::fmt;
+
+use eframe::egui::{self, Key, KeyboardShortcut, Modifiers};
+
+use crate::i18n::Locale;
+
+/// Something a keyboard shortcut can do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShortcutAction {
+    Undo,
+    Redo,
+    Increment,
+    Decrement,
+    ShowDialog,
+    ToggleScaling,
+    FocusSearch,
+    ZoomIn,
+    ZoomOut,
+    ResetZoom,
+}
+
+impl ShortcutAction {
+    /// Message key of what the action does, as listed in Help → Keyboard Shortcuts.
+    pub fn key(self) -> &'static str {
+        match self {
+            ShortcutAction::Undo => "shortcut.undo",
+            ShortcutAction::Redo => "shortcut.redo",
+            ShortcutAction::Increment => "shortcut.increment",
+            ShortcutAction::Decrement => "shortcut.decrement",
+            ShortcutAction::ShowDialog => "shortcut.show_dialog",
+            ShortcutAction::ToggleScaling => "shortcut.toggle_scaling",
+            ShortcutAction::FocusSearch => "shortcut.focus_search",
+            ShortcutAction::ZoomIn => "shortcut.zoom_in",
+            ShortcutAction::ZoomOut => "shortcut.zoom_out",
+            ShortcutAction::ResetZoom => "shortcut.reset_zoom",
+        }
+    }
+
+    /// English description for [`ShortcutAction::key`].
+    pub fn description(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+}
+
+/// A key combo that is already bound to another action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShortcutConflict {
+    pub shortcut: KeyboardShortcut,
+    pub existing: ShortcutAction,
+    pub rejected: ShortcutAction,
+}
+
+impl fmt::Display for ShortcutConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is already bound to {:?}, cannot bind it to {:?}",
+            self.shortcut.format(&egui::ModifierNames::NAMES, false),
+            self.existing,
+            self.rejected
+        )
+    }
+}
+
+impl std::error::Error for ShortcutConflict {}
+
+/// Bindings from key combos to [`ShortcutAction`]s.
+#[derive(Clone, Debug, Default)]
+pub struct ShortcutRegistry {
+    bindings: Vec<(KeyboardShortcut, ShortcutAction)>,
+}
+
+impl ShortcutRegistry {
+    /// The app's default bindings.
+    pub fn standard() -> Self {
+        let command = Modifiers::COMMAND;
+        let command_shift = Modifiers::COMMAND.plus(Modifiers::SHIFT);
+        let mut registry = Self::default();
+        for (modifiers, key, action) in [
+            (command, Key::Z, ShortcutAction::Undo),
+            (command_shift, Key::Z, ShortcutAction::Redo),
+            (command, Key::ArrowUp, ShortcutAction::Increment),
+            (command, Key::ArrowDown, ShortcutAction::Decrement),
+            (command, Key::D, ShortcutAction::ShowDialog),
+            (command_shift, Key::M, ShortcutAction::ToggleScaling),
+            (command, Key::F, ShortcutAction::FocusSearch),
+            (command, Key::Plus, ShortcutAction::ZoomIn),
+            // Plus is Shift+Equals on most layouts
+            (command, Key::Equals, ShortcutAction::ZoomIn),
+            (command, Key::Minus, ShortcutAction::ZoomOut),
+            (command, Key::Num0, ShortcutAction::ResetZoom),
+        ] {
+            registry
+                .register(KeyboardShortcut::new(modifiers, key), action)
+                .expect("standard shortcuts are distinct");
+        }
+        registry
+    }
+
+    /// Bind `shortcut` to `action`, unless an equivalent combo is already bound.
+    ///
+    /// Ctrl, Cmd and the platform-neutral `COMMAND` modifier count as the same key
+    /// here, so a binding can't collide on one platform and not another.
+    pub fn register(
+        &mut self,
+        shortcut: KeyboardShortcut,
+        action: ShortcutAction,
+    ) -> Result<(), ShortcutConflict> {
+        if let Some(&(_, existing)) = self
+            .bindings
+            .iter()
+            .find(|(bound, _)| equivalent(bound, &shortcut))
+        {
+            return Err(ShortcutConflict {
+                shortcut,
+                existing,
+                rejected: action,
+            });
+        }
+        self.bindings.push((shortcut, action));
+        Ok(())
+    }
+
+    /// Every binding, in registration order.
+    pub fn bindings(&self) -> &[(KeyboardShortcut, ShortcutAction)] {
+        &self.bindings
+    }
+
+    pub fn action_for(&self, shortcut: KeyboardShortcut) -> Option<ShortcutAction> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == shortcut)
+            .map(|&(_, action)| action)
+    }
+
+    /// The first shortcut bound to `action`.
+    pub fn shortcut_for(&self, action: ShortcutAction) -> Option<KeyboardShortcut> {
+        self.bindings
+            .iter()
+            .find(|(_, bound)| *bound == action)
+            .map(|&(shortcut, _)| shortcut)
+    }
+
+    /// Consume the pressed shortcut, if any, and return its action.
+    ///
+    /// Bindings with more modifiers are checked first, because egui matches
+    /// Ctrl+Z even while Shift is also held.
+    pub fn consume(&self, input: &mut egui::InputState) -> Option<ShortcutAction> {
+        let mut by_specificity: Vec<_> = self.bindings.iter().collect();
+        by_specificity.sort_by_key(|(shortcut, _)| std::cmp::Reverse(modifier_count(shortcut)));
+        by_specificity
+            .into_iter()
+            .find(|(shortcut, _)| input.consume_shortcut(shortcut))
+            .map(|&(_, action)| action)
+    }
+}
+
+fn equivalent(a: &KeyboardShortcut, b: &KeyboardShortcut) -> bool {
+    let command = |m: Modifiers| m.command || m.ctrl || m.mac_cmd;
+    a.logical_key == b.logical_key
+        && a.modifiers.alt == b.modifiers.alt
+        && a.modifiers.shift == b.modifiers.shift
+        && command(a.modifiers) == command(b.modifiers)
+}
+
+fn modifier_count(shortcut: &KeyboardShortcut) -> u8 {
+    let m = shortcut.modifiers;
+    [m.alt, m.ctrl, m.shift, m.mac_cmd, m.command]
+        .into_iter()
+        .map(u8::from)
+        .sum()
+}
@@ -0,0 +1,332 @@
+//! Test support, behind the `testing` cargo feature.
+//!
+//! [`demo_harness`] builds an `egui_kittest` harness that owns a [`DemoApp`] as its
+//! state, so tests can read and change the app's fields between frames:
+//!
+//! ```no_run
+//! use egui_kittest::kittest::Queryable;
+//! use egui_kittest_demo::testing::{Viewport, demo_harness};
+//!
+//! let mut harness = demo_harness(Viewport::Wide, |app| app.counter = 2);
+//! harness.get_by_label("Increment").click();
+//! harness.run();
+//! assert_eq!(harness.state().counter, 3);
+//! ```
+//!
+//! [`press`] and [`click_menu_item`] drive the app like a user would, and [`fixture`]
+//! locates the files under `tests/fixtures`; [`TempFile`] is a scratch file that
+//! cleans up after itself.
+//!
+//! [`audit_accessibility`] walks a harness's AccessKit tree and reports controls that
+//! assistive technology can't name or reach.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use eframe::egui;
+use egui::accesskit::{NodeId, Role};
+use egui_kittest::kittest::{NodeT, Queryable};
+use egui_kittest::{Harness, Node};
+
+use crate::DemoApp;
+
+/// Window sizes at the widths the responsive layout is documented for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Viewport {
+    /// 360 px: stacked layout, one card column.
+    Narrow,
+    /// 820 px: side panel and central area, two card columns.
+    Medium,
+    /// 1280 px: three card columns.
+    Wide,
+}
+
+impl Viewport {
+    pub const ALL: [Viewport; 3] = [Viewport::Narrow, Viewport::Medium, Viewport::Wide];
+
+    pub fn width(self) -> f32 {
+        match self {
+            Viewport::Narrow => 360.0,
+            Viewport::Medium => 820.0,
+            Viewport::Wide => 1280.0,
+        }
+    }
+
+    /// Window size in points; every preset is 720 points tall.
+    pub fn size(self) -> egui::Vec2 {
+        egui::vec2(self.width(), 720.0)
+    }
+}
+
+impl From<Viewport> for egui::Vec2 {
+    fn from(viewport: Viewport) -> Self {
+        viewport.size()
+    }
+}
+
+/// A harness running [`DemoApp::new`] at `size`, after `config` has set it up.
+///
+/// The app is the harness state: use `harness.state()` / `harness.state_mut()` to
+/// inspect or change it. The harness has already run its first frame when this returns.
+pub fn demo_harness(
+    size: impl Into<egui::Vec2>,
+    config: impl FnOnce(&mut DemoApp),
+) -> Harness<'static, DemoApp> {
+    let mut app = DemoApp::new();
+    config(&mut app);
+    Harness::builder().with_size(size).build_eframe(|_| app)
+}
+
+/// Press `key` with `modifiers` held and run the frames it causes.
+pub fn press<State>(harness: &mut Harness<'_, State>, modifiers: egui::Modifiers, key: egui::Key) {
+    harness.key_press_modifiers(modifiers, key);
+    harness.run();
+}
+
+/// Open the menu labelled `menu` and click its entry labelled `item`.
+pub fn click_menu_item<State>(harness: &mut Harness<'_, State>, menu: &str, item: &str) {
+    harness.get_by_label(menu).click();
+    harness.run();
+    harness.get_by_label(item).click();
+    harness.run();
+}
+
+/// Path of `name` in the crate's `tests/fixtures` directory.
+pub fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// A `.ron` file path in a temp dir of its own, removed with the dir when this is
+/// dropped, even if the test fails. The dir is named after the process and `name`, so
+/// tests running in parallel don't collide.
+pub struct TempFile {
+    dir: PathBuf,
+    pub path: PathBuf,
+}
+
+impl TempFile {
+    /// Create the dir; the file itself is left for the test to write.
+    pub fn new(name: &str) -> Self {
+        let dir =
+            std::env::temp_dir().join(format!("egui_kittest_demo_{}_{name}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join(format!("{name}.ron"));
+        Self { dir, path }
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// A problem found by [`audit_accessibility`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccessibilityIssue {
+    /// A control with no label for a screen reader to announce.
+    UnlabeledControl {
+        role: Role,
+        rect: Option<egui::Rect>,
+    },
+    /// A text input with no label, typically a `ui.label` drawn next to it but not
+    /// linked with `labelled_by`.
+    UnlabeledTextInput {
+        role: Role,
+        rect: Option<egui::Rect>,
+    },
+    /// Sibling controls sharing a label, which a screen reader can't tell apart.
+    DuplicateSiblingLabels { label: String, count: usize },
+    /// An enabled control that Tab never moves focus to.
+    UnreachableByTab {
+        role: Role,
+        label: Option<String>,
+        rect: Option<egui::Rect>,
+    },
+}
+
+impl fmt::Display for AccessibilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessibilityIssue::UnlabeledControl { role, rect } => {
+                write!(f, "{role:?} {} has no label", Position(*rect))
+            }
+            AccessibilityIssue::UnlabeledTextInput { role, rect } => {
+                write!(
+                    f,
+                    "{role:?} {} is not labelled by any label",
+                    Position(*rect)
+                )
+            }
+            AccessibilityIssue::DuplicateSiblingLabels { label, count } => {
+                write!(f, "{count} sibling controls are all labelled {label:?}")
+            }
+            AccessibilityIssue::UnreachableByTab { role, label, rect } => match label {
+                Some(label) => write!(f, "{role:?} {label:?} is not reachable by Tab"),
+                None => write!(f, "{role:?} {} is not reachable by Tab", Position(*rect)),
+            },
+        }
+    }
+}
+
+// Where an issue's node is; custom AccessKit nodes may have no bounds at all
+struct Position(Option<egui::Rect>);
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(rect) => write!(f, "at {rect:?}"),
+            None => write!(f, "without bounds"),
+        }
+    }
+}
+
+/// The issues found by one [`audit_accessibility`] run, in tree order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessibilityReport {
+    pub issues: Vec<AccessibilityIssue>,
+}
+
+impl AccessibilityReport {
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for AccessibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_clean() {
+            return write!(f, "no accessibility issues");
+        }
+        write!(f, "{} accessibility issue(s):", self.issues.len())?;
+        for issue in &self.issues {
+            write!(f, "\n  - {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Walk the harness's AccessKit tree and report unlabeled controls, unlabeled text
+/// inputs, duplicate labels among siblings and controls that Tab can't reach.
+///
+/// Finding the Tab order presses Tab until focus cycles, so this runs frames; focus is
+/// released again before it returns.
+pub fn audit_accessibility<State>(harness: &mut Harness<'_, State>) -> AccessibilityReport {
+    let mut issues = Vec::new();
+    let mut tab_targets = Vec::new();
+    let mut labels_by_parent: HashMap<NodeId, Vec<String>> = HashMap::new();
+    for node in harness.root().children_recursive() {
+        let accesskit = node.accesskit_node();
+        if !is_control(&node) {
+            continue;
+        }
+        let role = accesskit.role();
+        let label = accesskit.label().filter(|label| !label.trim().is_empty());
+        match &label {
+            None if accesskit.is_text_input() => {
+                issues.push(AccessibilityIssue::UnlabeledTextInput {
+                    role,
+                    rect: bounds(&node),
+                });
+            }
+            None => issues.push(AccessibilityIssue::UnlabeledControl {
+                role,
+                rect: bounds(&node),
+            }),
+            Some(label) => {
+                if let Some(parent) = accesskit.parent_id() {
+                    labels_by_parent
+                        .entry(parent)
+                        .or_default()
+                        .push(label.clone());
+                }
+            }
+        }
+        if !accesskit.is_disabled() {
+            tab_targets.push((accesskit.id(), role, label, bounds(&node)));
+        }
+    }
+
+    let mut duplicates: Vec<_> = labels_by_parent
+        .into_values()
+        .flat_map(|labels| {
+            let mut counts: HashMap<String, usize> = HashMap::new();
+            for label in labels {
+                *counts.entry(label).or_default() += 1;
+            }
+            counts.into_iter().filter(|(_, count)| *count > 1)
+        })
+        .collect();
+    duplicates.sort();
+    issues.extend(
+        duplicates
+            .into_iter()
+            .map(|(label, count)| AccessibilityIssue::DuplicateSiblingLabels { label, count }),
+    );
+
+    let reached = tab_order(harness, tab_targets.len());
+    issues.extend(
+        tab_targets
+            .into_iter()
+            .filter(|(id, ..)| !reached.contains(id))
+            .map(
+                |(_, role, label, rect)| AccessibilityIssue::UnreachableByTab { role, label, rect },
+            ),
+    );
+    AccessibilityReport { issues }
+}
+
+// egui gives its own drag regions (scroll areas, scroll bars, panel resize handles)
+// `Role::Unknown`, and the viewport root is a focusable `Window`; neither is a control
+fn is_control(node: &Node<'_>) -> bool {
+    let accesskit = node.accesskit_node();
+    accesskit.is_focusable()
+        && !accesskit.is_hidden()
+        && !matches!(accesskit.role(), Role::Unknown | Role::Window)
+}
+
+// `Node::rect` panics for nodes without bounds, which custom AccessKit nodes may lack
+fn bounds(node: &Node<'_>) -> Option<egui::Rect> {
+    node.accesskit_node().bounding_box().map(|rect| {
+        egui::Rect::from_min_max(
+            egui::pos2(rect.x0 as f32, rect.y0 as f32),
+            egui::pos2(rect.x1 as f32, rect.y1 as f32),
+        )
+    })
+}
+
+// Press Tab until focus comes back around, collecting every node it lands on
+fn tab_order<State>(harness: &mut Harness<'_, State>, controls: usize) -> HashSet<NodeId> {
+    let mut reached = HashSet::new();
+    let mut first = None;
+    // Room for egui's own focusable regions, which Tab also visits
+    for _ in 0..=2 * controls + 1 {
+        harness.key_press(egui::Key::Tab);
+        harness.run();
+        let Some(focused) = focused_node(harness) else {
+            break;
+        };
+        if first == Some(focused) {
+            break;
+        }
+        first.get_or_insert(focused);
+        reached.insert(focused);
+    }
+    if let Some(id) = harness.ctx.memory(|memory| memory.focused()) {
+        harness.ctx.memory_mut(|memory| memory.surrender_focus(id));
+        harness.run();
+    }
+    reached
+}
+
+fn focused_node<State>(harness: &Harness<'_, State>) -> Option<NodeId> {
+    harness
+        .root()
+        .children_recursive()
+        .map(|node| node.accesskit_node())
+        .find(|node| node.is_focused())
+        .map(|node| node.id())
+}
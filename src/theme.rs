@@ -0,0 +1,390 @@
+//! The app's colour theme setting, and custom themes read from files.
+//!
+//! Light, Dark and System map onto egui's own dark/light styles. High Contrast is a
+//! dark theme with black backgrounds, white text and heavier widget outlines. A
+//! [`ThemeFile`] layers branded colours, strokes, radii and font sizes on top of
+//! whichever of those is selected.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use eframe::egui::{self, Color32, Stroke};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Locale;
+
+/// Theme chosen in View → Theme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Theme {
+    /// Follow the operating system's light or dark preference.
+    #[default]
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 4] = [
+        Theme::System,
+        Theme::Light,
+        Theme::Dark,
+        Theme::HighContrast,
+    ];
+
+    /// Message key of the text used in the `Theme: …` semantic label and the View menu.
+    pub fn key(self) -> &'static str {
+        match self {
+            Theme::System => "theme.system",
+            Theme::Light => "theme.light",
+            Theme::Dark => "theme.dark",
+            Theme::HighContrast => "theme.high_contrast",
+        }
+    }
+
+    /// English text for [`Theme::key`].
+    pub fn label(self) -> &'static str {
+        Locale::En.text(self.key())
+    }
+
+    /// egui's dark/light preference for this theme.
+    pub fn preference(self) -> egui::ThemePreference {
+        match self {
+            Theme::System => egui::ThemePreference::System,
+            Theme::Light => egui::ThemePreference::Light,
+            Theme::Dark | Theme::HighContrast => egui::ThemePreference::Dark,
+        }
+    }
+
+    /// Visuals for this theme, where `resolved` is the dark/light theme egui picked.
+    pub fn visuals(self, resolved: egui::Theme) -> egui::Visuals {
+        match self {
+            Theme::System => resolved.default_visuals(),
+            Theme::Light => egui::Visuals::light(),
+            Theme::Dark => egui::Visuals::dark(),
+            Theme::HighContrast => high_contrast_visuals(),
+        }
+    }
+}
+
+/// White on black, with yellow focus and selection and two-point widget outlines.
+fn high_contrast_visuals() -> egui::Visuals {
+    let mut visuals = egui::Visuals::dark();
+    visuals.override_text_color = Some(Color32::WHITE);
+    visuals.weak_text_color = Some(Color32::from_gray(220));
+    visuals.panel_fill = Color32::BLACK;
+    visuals.window_fill = Color32::BLACK;
+    visuals.extreme_bg_color = Color32::BLACK;
+    visuals.faint_bg_color = Color32::from_gray(24);
+    visuals.code_bg_color = Color32::from_gray(24);
+    visuals.window_stroke = Stroke::new(2.0, Color32::WHITE);
+    visuals.hyperlink_color = Color32::from_rgb(0, 255, 255);
+    visuals.warn_fg_color = Color32::YELLOW;
+    visuals.error_fg_color = Color32::from_rgb(255, 96, 96);
+    visuals.selection.bg_fill = Color32::from_rgb(0, 0, 160);
+    visuals.selection.stroke = Stroke::new(2.0, Color32::YELLOW);
+
+    let widgets = &mut visuals.widgets;
+    widgets.noninteractive.bg_stroke = Stroke::new(1.0, Color32::WHITE);
+    widgets.noninteractive.fg_stroke = Stroke::new(1.0, Color32::WHITE);
+    for (state, outline) in [
+        (&mut widgets.inactive, Color32::WHITE),
+        (&mut widgets.hovered, Color32::YELLOW),
+        (&mut widgets.active, Color32::YELLOW),
+        (&mut widgets.open, Color32::WHITE),
+    ] {
+        state.bg_fill = Color32::BLACK;
+        state.weak_bg_fill = Color32::BLACK;
+        state.bg_stroke = Stroke::new(2.0, outline);
+        state.fg_stroke = Stroke::new(2.0, outline);
+    }
+    visuals
+}
+
+/// A colour written as `"#rrggbb"` or `"#rrggbbaa"` in a theme file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct HexColor(pub Color32);
+
+impl TryFrom<String> for HexColor {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        Color32::from_hex(&text)
+            .map(HexColor)
+            .map_err(|_| format!("expected a color like \"#1e66f5\", found \"{text}\""))
+    }
+}
+
+impl From<HexColor> for String {
+    fn from(color: HexColor) -> Self {
+        color.0.to_hex()
+    }
+}
+
+/// Colours for one widget state. Unset fields keep the built-in theme's value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WidgetColors {
+    pub fill: Option<HexColor>,
+    pub stroke: Option<HexColor>,
+    pub text: Option<HexColor>,
+}
+
+impl WidgetColors {
+    fn apply(&self, widget: &mut egui::style::WidgetVisuals) {
+        if let Some(HexColor(fill)) = self.fill {
+            widget.bg_fill = fill;
+            widget.weak_bg_fill = fill;
+        }
+        if let Some(HexColor(stroke)) = self.stroke {
+            widget.bg_stroke.color = stroke;
+        }
+        if let Some(HexColor(text)) = self.text {
+            widget.fg_stroke.color = text;
+        }
+    }
+}
+
+/// Colours per widget state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeWidgets {
+    pub noninteractive: WidgetColors,
+    pub inactive: WidgetColors,
+    pub hovered: WidgetColors,
+    pub active: WidgetColors,
+    pub open: WidgetColors,
+}
+
+/// Font sizes in points, before any Style-mode scaling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FontSizes {
+    pub small: Option<f32>,
+    pub body: Option<f32>,
+    pub button: Option<f32>,
+    pub heading: Option<f32>,
+    pub monospace: Option<f32>,
+}
+
+impl FontSizes {
+    fn entries(&self) -> [(&'static str, egui::TextStyle, Option<f32>); 5] {
+        [
+            ("small", egui::TextStyle::Small, self.small),
+            ("body", egui::TextStyle::Body, self.body),
+            ("button", egui::TextStyle::Button, self.button),
+            ("heading", egui::TextStyle::Heading, self.heading),
+            ("monospace", egui::TextStyle::Monospace, self.monospace),
+        ]
+    }
+}
+
+/// A custom theme read from a RON file. Every field is optional.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeFile {
+    /// Shown as `Custom theme: …`.
+    pub name: String,
+    pub text: Option<HexColor>,
+    pub panel_fill: Option<HexColor>,
+    pub window_fill: Option<HexColor>,
+    pub hyperlink: Option<HexColor>,
+    pub selection: Option<HexColor>,
+    /// Focus and selection outline.
+    pub selection_stroke: Option<HexColor>,
+    pub widgets: ThemeWidgets,
+    /// Width of widget and window outlines, in points.
+    pub stroke_width: Option<f32>,
+    /// Corner radius of widgets, windows and menus, in points.
+    pub corner_radius: Option<u8>,
+    pub font_sizes: FontSizes,
+}
+
+impl ThemeFile {
+    /// Read and check a theme file.
+    pub fn load(path: &Path) -> Result<Self, ThemeError> {
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&text)
+    }
+
+    /// Parse a theme from RON text. Errors name the line and column.
+    pub fn parse(text: &str) -> Result<Self, ThemeError> {
+        // Optional fields are written bare, without `Some(..)`
+        let theme: Self = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(text)
+            .map_err(|err| ThemeError::Format(err.to_string()))?;
+        theme.check()?;
+        Ok(theme)
+    }
+
+    fn check(&self) -> Result<(), ThemeError> {
+        if let Some(width) = self.stroke_width
+            && !(width.is_finite() && width >= 0.0)
+        {
+            return Err(ThemeError::StrokeWidth(width));
+        }
+        for (field, _, size) in self.font_sizes.entries() {
+            if let Some(size) = size
+                && !(size.is_finite() && size > 0.0)
+            {
+                return Err(ThemeError::FontSize { field, size });
+            }
+        }
+        Ok(())
+    }
+
+    /// Write this theme's settings over `style`.
+    pub fn apply(&self, style: &mut egui::Style) {
+        let visuals = &mut style.visuals;
+        if let Some(HexColor(text)) = self.text {
+            visuals.override_text_color = Some(text);
+        }
+        if let Some(HexColor(fill)) = self.panel_fill {
+            visuals.panel_fill = fill;
+        }
+        if let Some(HexColor(fill)) = self.window_fill {
+            visuals.window_fill = fill;
+        }
+        if let Some(HexColor(color)) = self.hyperlink {
+            visuals.hyperlink_color = color;
+        }
+        if let Some(HexColor(fill)) = self.selection {
+            visuals.selection.bg_fill = fill;
+        }
+        if let Some(HexColor(stroke)) = self.selection_stroke {
+            visuals.selection.stroke.color = stroke;
+        }
+
+        let widgets = &mut visuals.widgets;
+        self.widgets
+            .noninteractive
+            .apply(&mut widgets.noninteractive);
+        self.widgets.inactive.apply(&mut widgets.inactive);
+        self.widgets.hovered.apply(&mut widgets.hovered);
+        self.widgets.active.apply(&mut widgets.active);
+        self.widgets.open.apply(&mut widgets.open);
+        let states = [
+            &mut widgets.noninteractive,
+            &mut widgets.inactive,
+            &mut widgets.hovered,
+            &mut widgets.active,
+            &mut widgets.open,
+        ];
+        for widget in states {
+            if let Some(width) = self.stroke_width {
+                widget.bg_stroke.width = width;
+            }
+            if let Some(radius) = self.corner_radius {
+                widget.corner_radius = radius.into();
+            }
+        }
+        if let Some(width) = self.stroke_width {
+            visuals.window_stroke.width = width;
+        }
+        if let Some(radius) = self.corner_radius {
+            visuals.window_corner_radius = radius.into();
+            visuals.menu_corner_radius = radius.into();
+        }
+
+        for (_, text_style, size) in self.font_sizes.entries() {
+            if let Some(size) = size
+                && let Some(font_id) = style.text_styles.get_mut(&text_style)
+            {
+                font_id.size = size;
+            }
+        }
+    }
+}
+
+/// Why a theme file could not be used.
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(std::io::Error),
+    Format(String),
+    /// `stroke_width` is negative or not a number.
+    StrokeWidth(f32),
+    /// The `font_sizes` entry named `field` is not a positive number.
+    FontSize {
+        field: &'static str,
+        size: f32,
+    },
+}
+
+impl ThemeError {
+    /// The error's message in `locale`; [`fmt::Display`] gives the English one.
+    pub fn message_in(&self, locale: Locale) -> String {
+        match self {
+            ThemeError::Io(err) => locale.io_error(err),
+            ThemeError::Format(err) => locale.format("error.invalid_theme", &[("error", err)]),
+            ThemeError::StrokeWidth(width) => {
+                locale.format("error.theme_stroke_width", &[("value", width)])
+            }
+            ThemeError::FontSize { field, size } => {
+                locale.format("error.theme_font_size", &[("field", field), ("size", size)])
+            }
+        }
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message_in(Locale::En))
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<std::io::Error> for ThemeError {
+    fn from(err: std::io::Error) -> Self {
+        ThemeError::Io(err)
+    }
+}
+
+/// Seconds between checks of a watched theme file.
+pub const THEME_POLL_INTERVAL: f64 = 0.5;
+
+/// Notices when a theme file is modified on disk.
+#[derive(Clone, Debug)]
+pub struct ThemeFileWatch {
+    pub path: PathBuf,
+    modified: Option<SystemTime>,
+    polled_at: Option<f64>,
+}
+
+impl ThemeFileWatch {
+    /// Start watching `path` from its current modification time.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self {
+            modified: modified(&path),
+            path,
+            polled_at: None,
+        }
+    }
+
+    /// Whether the file was modified since the last check.
+    ///
+    /// `now` is in seconds; the disk is checked at most every [`THEME_POLL_INTERVAL`].
+    pub fn poll(&mut self, now: f64) -> bool {
+        if self
+            .polled_at
+            .is_some_and(|polled_at| now - polled_at < THEME_POLL_INTERVAL)
+        {
+            return false;
+        }
+        self.polled_at = Some(now);
+        let modified = modified(&self.path);
+        let changed = modified != self.modified;
+        self.modified = modified;
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
@@ -0,0 +1,179 @@
+//! Validation rules for the Name/Age form.
+//!
+//! Each field carries a list of [`Rule`]s; the first rule that fails provides the
+//! field's inline error. The form is valid when no field has an error, which gates
+//! File → Save. Field labels are message keys, so errors can be shown in any [`Locale`].
+
+use std::ops::RangeInclusive;
+
+use crate::i18n::Locale;
+
+/// The value of a form field, as seen by a [`Rule`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldValue<'a> {
+    Text(&'a str),
+    Number(i64),
+}
+
+/// A single check on a field. Rules that don't apply to a value's kind pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// Text must contain something other than whitespace.
+    Required,
+    /// Text must be at most this many characters.
+    MaxLength(usize),
+    /// Text may only contain letters and the listed characters.
+    LettersAnd(&'static str),
+    /// Number must lie in the range.
+    Range(RangeInclusive<i64>),
+}
+
+impl Rule {
+    /// The English error for `value` in the field labelled `field`, if the rule fails.
+    pub fn check(&self, field: &str, value: FieldValue<'_>) -> Option<String> {
+        self.check_in(Locale::En, field, value)
+    }
+
+    /// [`Rule::check`] in `locale`. `field` is looked up as a message key, and used
+    /// as is if there is no such key.
+    pub fn check_in(&self, locale: Locale, field: &str, value: FieldValue<'_>) -> Option<String> {
+        let field = locale.text(field);
+        match (self, value) {
+            (Rule::Required, FieldValue::Text(text)) if text.trim().is_empty() => {
+                Some(locale.format("validation.required", &[("field", &field)]))
+            }
+            (Rule::MaxLength(max), FieldValue::Text(text)) if text.chars().count() > *max => Some(
+                locale.format_plural("validation.max_length", *max as u64, &[("field", &field)]),
+            ),
+            (Rule::LettersAnd(extra), FieldValue::Text(text)) => text
+                .chars()
+                .find(|c| !c.is_alphabetic() && !extra.contains(*c))
+                .map(|c| {
+                    locale.format(
+                        "validation.invalid_char",
+                        &[("field", &field), ("char", &c)],
+                    )
+                }),
+            (Rule::Range(range), FieldValue::Number(n)) if !range.contains(&n) => {
+                Some(locale.format(
+                    "validation.range",
+                    &[
+                        ("field", &field),
+                        ("min", range.start()),
+                        ("max", range.end()),
+                    ],
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The rules for one field, with the label (or its message key) used in its messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldRules {
+    pub label: &'static str,
+    pub rules: Vec<Rule>,
+}
+
+impl FieldRules {
+    pub fn new(label: &'static str, rules: impl IntoIterator<Item = Rule>) -> Self {
+        Self {
+            label,
+            rules: rules.into_iter().collect(),
+        }
+    }
+
+    /// The first failing rule's message.
+    pub fn validate(&self, value: FieldValue<'_>) -> Option<String> {
+        self.validate_in(Locale::En, value)
+    }
+
+    /// [`FieldRules::validate`] in `locale`.
+    pub fn validate_in(&self, locale: Locale, value: FieldValue<'_>) -> Option<String> {
+        self.rules
+            .iter()
+            .find_map(|rule| rule.check_in(locale, self.label, value))
+    }
+}
+
+/// Ages the form accepts; the age `DragValue` is limited to the same range.
+pub const AGE_RANGE: RangeInclusive<u32> = 0..=120;
+
+/// Rules for every field of the Name/Age form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormValidator {
+    pub name: FieldRules,
+    pub age: FieldRules,
+}
+
+impl Default for FormValidator {
+    fn default() -> Self {
+        Self {
+            name: FieldRules::new(
+                "field.name",
+                [
+                    Rule::Required,
+                    Rule::MaxLength(40),
+                    Rule::LettersAnd(" -'."),
+                ],
+            ),
+            age: FieldRules::new(
+                "field.age",
+                [Rule::Range(
+                    i64::from(*AGE_RANGE.start())..=i64::from(*AGE_RANGE.end()),
+                )],
+            ),
+        }
+    }
+}
+
+impl FormValidator {
+    pub fn validate(&self, name: &str, age: u32) -> FormErrors {
+        self.validate_in(Locale::En, name, age)
+    }
+
+    /// [`FormValidator::validate`] with messages in `locale`.
+    pub fn validate_in(&self, locale: Locale, name: &str, age: u32) -> FormErrors {
+        FormErrors {
+            name: self.name.validate_in(locale, FieldValue::Text(name)),
+            age: self.age.validate_in(locale, FieldValue::Number(age.into())),
+        }
+    }
+}
+
+/// Inline error per field; `None` means the field is valid.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormErrors {
+    pub name: Option<String>,
+    pub age: Option<String>,
+}
+
+impl FormErrors {
+    pub fn is_valid(&self) -> bool {
+        self.name.is_none() && self.age.is_none()
+    }
+}
+
+/// Form fields the user has edited or left; errors are only shown for these, so a
+/// fresh form doesn't open with a wall of red.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TouchedFields {
+    pub name: bool,
+    pub age: bool,
+}
+
+impl TouchedFields {
+    pub const ALL: TouchedFields = TouchedFields {
+        name: true,
+        age: true,
+    };
+
+    /// `errors` limited to the touched fields.
+    pub fn visible(self, errors: FormErrors) -> FormErrors {
+        FormErrors {
+            name: errors.name.filter(|_| self.name),
+            age: errors.age.filter(|_| self.age),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use egui::accesskit::{Action, Rect, Role};
+use egui_kittest::{
+    Harness,
+    kittest::{NodeT, Queryable},
+};
+use egui_kittest_demo::testing::{AccessibilityIssue, Viewport, audit_accessibility, demo_harness};
+use egui_kittest_demo::{Card, StaticCards};
+
+#[test]
+fn audit_reports_each_kind_of_issue() {
+    let mut name = String::new();
+    let mut harness = Harness::new_ui(|ui| {
+        let _ = ui.button("OK");
+        let _ = ui.button("OK");
+        let _ = ui.button("");
+        ui.text_edit_singleline(&mut name);
+        // A custom widget that advertises focus but never registers for it
+        ui.ctx()
+            .accesskit_node_builder(egui::Id::new("ghost"), |node| {
+                node.set_role(Role::Button);
+                node.set_label("Ghost");
+                node.set_bounds(Rect::new(0.0, 200.0, 80.0, 220.0));
+                node.add_action(Action::Focus);
+            });
+    });
+    let report = audit_accessibility(&mut harness);
+
+    let kinds: Vec<&str> = report
+        .issues
+        .iter()
+        .map(|issue| match issue {
+            AccessibilityIssue::UnlabeledControl { .. } => "unlabeled",
+            AccessibilityIssue::UnlabeledTextInput { .. } => "text input",
+            AccessibilityIssue::DuplicateSiblingLabels { .. } => "duplicate",
+            AccessibilityIssue::UnreachableByTab { .. } => "unreachable",
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        ["unlabeled", "text input", "duplicate", "unreachable"],
+        "{report}"
+    );
+    let text = report.to_string();
+    assert!(text.starts_with("4 accessibility issue(s):"), "{text}");
+    assert!(
+        text.contains("2 sibling controls are all labelled \"OK\""),
+        "{text}"
+    );
+    assert!(
+        text.contains("Button \"Ghost\" is not reachable by Tab"),
+        "{text}"
+    );
+}
+
+// Custom nodes need not set bounds; the audit reports them instead of panicking
+#[test]
+fn audit_handles_focusable_nodes_without_bounds() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.ctx()
+            .accesskit_node_builder(egui::Id::new("boundless"), |node| {
+                node.set_role(Role::Button);
+                node.add_action(Action::Focus);
+            });
+    });
+    let report = audit_accessibility(&mut harness);
+
+    assert_eq!(
+        report.issues,
+        [
+            AccessibilityIssue::UnlabeledControl {
+                role: Role::Button,
+                rect: None,
+            },
+            AccessibilityIssue::UnreachableByTab {
+                role: Role::Button,
+                label: None,
+                rect: None,
+            },
+        ],
+        "{report}"
+    );
+    assert!(
+        report
+            .to_string()
+            .contains("Button without bounds has no label"),
+        "{report}"
+    );
+}
+
+// Collects every viewport's report so one failure shows all of them
+#[test]
+fn demo_app_passes_the_audit_at_every_viewport() {
+    let failures: Vec<String> = Viewport::ALL
+        .into_iter()
+        .filter_map(|viewport| {
+            let mut harness = demo_harness(viewport, |_| {});
+            let report = audit_accessibility(&mut harness);
+            (!report.is_clean()).then(|| format!("{viewport:?}: {report}"))
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn form_labels_name_their_inputs() {
+    for viewport in [Viewport::Narrow, Viewport::Wide] {
+        let harness = demo_harness(viewport, |_| {});
+        let name = harness.get_by_label("Name");
+        assert_eq!(
+            name.accesskit_node().role(),
+            Role::TextInput,
+            "{viewport:?}"
+        );
+        let age = harness.get_by_label("Age");
+        assert_eq!(
+            age.accesskit_node().role(),
+            Role::SpinButton,
+            "{viewport:?}"
+        );
+    }
+}
+
+// Two copies of the same card share a title; their controls must still be told apart
+#[test]
+fn duplicated_cards_keep_distinct_control_labels() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    for _ in 0..2 {
+        harness.get_by_label("More for Card 2").click();
+        harness.run();
+        harness.get_by_label("Duplicate").click();
+        harness.run();
+    }
+    assert_eq!(harness.query_all_by_label("Card 2 (copy)").count(), 2);
+    harness.get_by_label("Action for Card 2 (copy) (#7)");
+    harness.get_by_label("Action for Card 2 (copy) (#8)");
+    // A title only one card has keeps the short label
+    harness.get_by_label("Action for Card 2");
+
+    let report = audit_accessibility(&mut harness);
+    assert!(report.is_clean(), "{report}");
+}
+
+// Loaded data can repeat titles as well
+#[test]
+fn repeated_titles_from_data_keep_distinct_control_labels() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        let cards = (1..=3).map(|id| Card::new(id, "Same", "")).collect();
+        app.load_cards(&mut StaticCards(cards)).unwrap();
+    });
+    for id in 1..=3 {
+        harness.get_by_label(&format!("Details for Same (#{id})"));
+    }
+    let report = audit_accessibility(&mut harness);
+    assert!(report.is_clean(), "{report}");
+}
@@ -1,18 +1,12 @@
-use eframe::App;
 use egui::accesskit::Role;
 use egui::{self, Key, Vec2};
-use egui_kittest::{Harness, kittest::Queryable};
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{Viewport, demo_harness};
 
 // Keyboard typing into the TextInput should update the greeting label
 #[test]
 fn keyboard_name_input_updates_greeting() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-    let mut harness = Harness::builder()
-        .with_size(Vec2::new(420.0, 360.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(Vec2::new(420.0, 360.0), |_| {});
 
     let input = harness.get_by_role(Role::TextInput);
     input.focus();
@@ -26,13 +20,8 @@ fn keyboard_name_input_updates_greeting() {
 // Typing a value into the SpinButton should reflect in the age part of the greeting
 #[test]
 fn spinbutton_type_updates_age() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-    let mut harness = Harness::builder()
-        .with_size(Vec2::new(460.0, 360.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    // The greeting is only shown for a valid form, which needs a name
+    let mut harness = demo_harness(Vec2::new(460.0, 360.0), |app| app.name = "Eve".into());
 
     let age = harness.get_by_role(Role::SpinButton);
     age.focus();
@@ -50,13 +39,7 @@ fn spinbutton_type_updates_age() {
 // At wide widths (3 columns), the first row cards should be left-to-right ordered by x
 #[test]
 fn wide_grid_geometry_columns_order() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-    let harness = Harness::builder()
-        .with_size(Vec2::new(1280.0, 720.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let harness = demo_harness(Viewport::Wide, |_| {});
     // Ensure we are in the 3-column layout
     harness.get_by_label("Columns: 3");
 
@@ -77,13 +60,7 @@ fn wide_grid_geometry_columns_order() {
 // On a very small window, request Card 6 be scrolled into view and assert it becomes visible
 #[test]
 fn scroll_to_view_offscreen_card() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-    let mut harness = Harness::builder()
-        .with_size(Vec2::new(320.0, 220.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(Vec2::new(320.0, 220.0), |_| {});
 
     let card6 = harness.get_by_label("Card 6");
     // Bring it into view explicitly, then validate its rect is within viewport
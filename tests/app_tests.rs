@@ -1,25 +1,17 @@
-use eframe::{App, Frame};
 use egui::accesskit::Role;
-use egui_kittest::{Harness, kittest::Queryable};
-
-// Import our main app struct
-use egui_kittest_demo::DemoApp;
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{Viewport, demo_harness};
 
 #[test]
 fn test_demo_app_basic_functionality() {
-    let mut app = DemoApp::new();
-
-    let mut harness = Harness::new(|ctx| {
-        let mut frame = eframe::Frame::_new_kittest();
-        app.update(ctx, &mut frame);
-    });
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
 
     // Test that the main heading is present
     harness.get_by_label("egui_kittest Demo App");
 
-    // Test that form fields are present
-    harness.get_by_label("Name:");
-    harness.get_by_label("Age:");
+    // Test that form fields are present, found through their labels
+    harness.get_by_label("Name");
+    harness.get_by_label("Age");
 
     // Test counter functionality
     harness.get_by_label("Counter: 0");
@@ -41,28 +33,20 @@ fn test_demo_app_basic_functionality() {
 
 #[tokio::test]
 async fn test_demo_app_name_input() {
-    let mut app = DemoApp::default();
-    let mut frame = Frame::_new_kittest();
-
     // Test name input
-    let mut harness = Harness::new_ui(|ui| {
-        app.update(ui.ctx(), &mut frame);
-    });
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
 
     // The TextInput has role TextInput, not searching by value due to duplicates
     let text_input = harness.get_by_role(Role::TextInput);
+    text_input.focus();
     text_input.type_text("Alice");
     harness.run();
+    assert_eq!(harness.state().name, "Alice");
 }
 
 #[test]
 fn test_demo_app_age_input() {
-    let mut app = DemoApp::new();
-
-    let mut harness = Harness::new(|ctx| {
-        let mut frame = eframe::Frame::_new_kittest();
-        app.update(ctx, &mut frame);
-    });
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
 
     // Test age input using SpinButton role
     let _age_input = harness.get_by_role(Role::SpinButton);
@@ -80,12 +64,7 @@ fn test_demo_app_age_input() {
 
 #[test]
 fn test_demo_app_dialog() {
-    let mut app = DemoApp::new();
-
-    let mut harness = Harness::new(|ctx| {
-        let mut frame = eframe::Frame::_new_kittest();
-        app.update(ctx, &mut frame);
-    });
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
 
     // Dialog should not be visible initially
     assert!(harness.query_by_label("Confirmation").is_none());
@@ -115,12 +94,7 @@ fn test_demo_app_dialog() {
 
 #[test]
 fn test_demo_app_dialog_no_button() {
-    let mut app = DemoApp::new();
-
-    let mut harness = Harness::new(|ctx| {
-        let mut frame = eframe::Frame::_new_kittest();
-        app.update(ctx, &mut frame);
-    });
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
 
     // Open dialog
     harness.get_by_label("Show Dialog").click();
@@ -139,12 +113,7 @@ fn test_demo_app_dialog_no_button() {
 
 #[test]
 fn test_demo_app_complete_workflow() {
-    let mut app = DemoApp::new();
-
-    let mut harness = Harness::new(|ctx| {
-        let mut frame = eframe::Frame::_new_kittest();
-        app.update(ctx, &mut frame);
-    });
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
 
     // Fill in name using TextInput role
     let name_input = harness.get_by_role(Role::TextInput);
@@ -180,13 +149,7 @@ fn test_demo_app_complete_workflow() {
 
 #[test]
 fn test_small_window_has_scrollbar_and_accessible_content() {
-    let mut app = DemoApp::new();
-    let mut harness = Harness::builder()
-        .with_size(egui::vec2(300.0, 200.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(300.0, 200.0), |_| {});
 
     // Heading should be present
     harness.get_by_label("egui_kittest Demo App");
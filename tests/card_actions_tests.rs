@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use egui_kittest::{Harness, kittest::Queryable};
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+use egui_kittest_demo::{Card, DemoApp, Locale};
+
+fn card_menu(harness: &mut Harness<'_, DemoApp>, card: &str, item: &str) {
+    harness.get_by_label(&format!("More for {card}")).click();
+    harness.run();
+    harness.get_by_label(item).click();
+    harness.run();
+}
+
+#[test]
+fn card_controls_have_unique_labels() {
+    let harness = demo_harness(Viewport::Medium, |_| {});
+    for n in 1..=6 {
+        for control in ["Action", "More", "Details"] {
+            harness.get_by_label(&format!("{control} for Card {n}"));
+        }
+    }
+    assert!(harness.query_by_label("Action").is_none());
+}
+
+#[test]
+fn details_opens_a_window_per_card() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    harness.get_by_label("Details for Card 3").click();
+    harness.run();
+    harness.get_by_label("Card 3 details");
+    harness.get_by_label("Tags: Layout, Accessibility");
+    harness.get_by_label("Pinned: No");
+
+    harness.get_by_label("Details for Card 5").click();
+    harness.run();
+    harness.get_by_label("Card 5 details");
+    harness.get_by_label("Card 3 details");
+    assert_eq!(harness.state().open_card_details.len(), 2);
+    // Opening details is not an edit
+    assert!(!harness.state().is_dirty());
+}
+
+#[test]
+fn more_menu_duplicates_deletes_and_pins() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    card_menu(&mut harness, "Card 2", "Duplicate");
+    harness.get_by_label("Card 2 (copy)");
+    harness.get_by_label("Showing 7 of 7 cards");
+
+    card_menu(&mut harness, "Card 4", "Delete");
+    assert!(harness.query_by_label("Card 4").is_none());
+    harness.get_by_label("Showing 6 of 6 cards");
+
+    card_menu(&mut harness, "Card 6", "Pin");
+    harness.get_by_label("Pinned");
+    // Pinned cards lead the grid
+    let pinned = harness.get_by_label("Card 6").rect();
+    let first = harness.get_by_label("Card 1").rect();
+    assert!(pinned.min.x < first.min.x);
+    assert!(pinned.min.y <= first.min.y);
+    let ids: Vec<u64> = harness.state().cards().iter().map(|card| card.id).collect();
+    assert_eq!(ids, [1, 2, 7, 3, 5, 6]);
+    assert!(harness.state().cards()[5].pinned);
+    assert!(harness.state().is_dirty());
+}
+
+#[test]
+fn action_runs_the_configured_command() {
+    let ran = Rc::new(RefCell::new(Vec::new()));
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        let log = Rc::clone(&ran);
+        app.card_command = Box::new(move |card: &Card, _: Locale| {
+            log.borrow_mut().push(card.id);
+            format!("Opened {}", card.title)
+        });
+    });
+    harness.get_by_label("Action for Card 4").click();
+    harness.run();
+    harness.get_by_label("Last action: Opened Card 4");
+    assert_eq!(*ran.borrow(), [4]);
+}
+
+#[test]
+fn default_action_reports_the_card() {
+    let mut app = DemoApp::new();
+    app.run_card_command(2);
+    assert_eq!(app.card_status.as_deref(), Some("Ran action for Card 2"));
+
+    app.locale = Locale::De;
+    app.run_card_command(2);
+    assert_eq!(
+        app.card_status.as_deref(),
+        Some("Aktion für Card 2 ausgeführt")
+    );
+}
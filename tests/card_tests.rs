@@ -0,0 +1,146 @@
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{TempFile, Viewport, demo_harness, fixture};
+use egui_kittest_demo::{
+    Card, CardSourceError, DemoApp, FileCardSource, PLACEHOLDER_ROWS, SortOrder, StaticCards,
+};
+
+#[test]
+fn load_cards_from_fixture_file() {
+    let harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.load_cards(&mut FileCardSource::new(fixture("cards.ron")))
+            .unwrap();
+    });
+    let cards = harness.state().cards();
+    assert_eq!(cards.len(), 3);
+    assert_eq!(cards[0].title, "Release checklist");
+    assert_eq!(cards[1].tags, ["Testing"]);
+    // Optional fields default when omitted
+    assert!(cards[2].tags.is_empty());
+    assert_eq!(cards[2].created, 0);
+
+    harness.get_by_label("Showing 3 of 3 cards");
+    harness.get_by_label("Snapshot review");
+    // Tag checkboxes come from the loaded data
+    harness.get_by_label("Process");
+}
+
+#[test]
+fn card_file_round_trip() {
+    let file = TempFile::new("cards");
+    let cards = Card::demo_set();
+    FileCardSource::write(&file.path, &cards).unwrap();
+
+    let mut app = DemoApp::new();
+    app.load_cards(&mut StaticCards(Vec::new())).unwrap();
+    app.load_cards(&mut FileCardSource::new(&file.path))
+        .unwrap();
+    assert_eq!(app.cards(), cards);
+}
+
+#[test]
+fn failed_load_keeps_current_cards() {
+    let mut app = DemoApp::new();
+    let err = app
+        .load_cards(&mut FileCardSource::new(fixture("does_not_exist.ron")))
+        .unwrap_err();
+    assert!(matches!(err, CardSourceError::Io(_)));
+    assert_eq!(app.cards(), Card::demo_set());
+}
+
+#[test]
+fn duplicate_ids_are_rejected() {
+    let mut app = DemoApp::new();
+    let err = app
+        .load_cards(&mut FileCardSource::new(fixture("duplicate_ids.ron")))
+        .unwrap_err();
+    assert!(matches!(err, CardSourceError::DuplicateId(1)));
+    assert_eq!(app.cards(), Card::demo_set());
+}
+
+// A bad cards file on the command line is reported in the app, which starts anyway
+#[test]
+fn bad_startup_cards_file_is_reported() {
+    let harness = demo_harness(Viewport::Medium, |app| {
+        app.load_cards_file(fixture("duplicate_ids.ron"));
+    });
+    harness.get_by_label_contains("Could not load cards from");
+    harness.get_by_label_contains("duplicate card id 1");
+    harness.get_by_label("Showing 6 of 6 cards");
+    assert!(!harness.state().is_dirty());
+}
+
+#[test]
+fn empty_state() {
+    let harness = demo_harness(Viewport::Medium, |app| {
+        app.load_cards(&mut StaticCards(Vec::new())).unwrap();
+    });
+    harness.get_by_label("No cards yet");
+    harness.get_by_label("Showing 0 of 0 cards");
+}
+
+#[test]
+fn single_card() {
+    let harness = demo_harness(Viewport::Wide, |app| {
+        let cards = vec![Card::new(1, "Lonely card", "Just me")];
+        app.load_cards(&mut StaticCards(cards)).unwrap();
+    });
+    harness.get_by_label("Columns: 3");
+    harness.get_by_label("Lonely card");
+    harness.get_by_label("Just me");
+    assert!(harness.query_by_label("No cards yet").is_none());
+}
+
+// Hundreds of cards stay reachable through the scroll area
+#[test]
+fn hundreds_of_cards() {
+    let cards = (1..=300)
+        .map(|n| Card::new(n, format!("Item {n}"), "Generated"))
+        .collect();
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.load_cards(&mut StaticCards(cards)).unwrap();
+    });
+    harness.get_by_label("Showing 300 of 300 cards");
+    // Only cards near the viewport are in the tree, so hop through the farthest one
+    while harness.query_by_label("Item 300").is_none() {
+        let stats = &harness.state().card_grid.stats;
+        let farthest = ((stats.rendered_rows.end + PLACEHOLDER_ROWS) * 2).min(300);
+        harness
+            .get_by_label(&format!("Item {farthest}"))
+            .scroll_to_me();
+        harness.run();
+    }
+    harness.get_by_label("Item 300").scroll_to_me();
+    harness.run();
+    assert!(harness.state().card_grid.stats.rendered_rows.contains(&149));
+}
+
+// A very long title wraps inside its column instead of widening the grid
+#[test]
+fn long_titles_wrap() {
+    let long = "A remarkably long card title that keeps going well past the width of any single column in the grid";
+    let harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        let cards = vec![Card::new(1, long, "Body"), Card::new(2, "Short", "Body")];
+        app.load_cards(&mut StaticCards(cards)).unwrap();
+    });
+    let title = harness.get_by_label(long).rect();
+    let short = harness.get_by_label("Short").rect();
+    assert!(
+        title.max.x < short.min.x,
+        "long title should stay in the first column: {title:?} vs {short:?}"
+    );
+    assert!(
+        title.height() > 2.0 * short.height(),
+        "long title should wrap onto several lines: {title:?}"
+    );
+}
+
+#[test]
+fn sort_by_created() {
+    let harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.load_cards(&mut StaticCards(Card::demo_set())).unwrap();
+        app.filters.sort = SortOrder::NewestFirst;
+    });
+    let newest = harness.get_by_label("Card 6").rect();
+    let next = harness.get_by_label("Card 5").rect();
+    assert!(newest.min.x < next.min.x);
+}
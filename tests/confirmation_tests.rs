@@ -0,0 +1,120 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use egui_kittest::{Harness, kittest::Queryable};
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+use egui_kittest_demo::{ConfirmOutcome, ConfirmationDialog, DemoApp};
+
+fn open_dialog(harness: &mut Harness<'_, DemoApp>) {
+    harness.get_by_label("Show Dialog").click();
+    harness.run();
+    harness.get_by_label("Confirmation");
+}
+
+#[test]
+fn yes_and_no_are_told_apart() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    assert!(harness.query_by_label_contains("Last choice").is_none());
+
+    open_dialog(&mut harness);
+    harness.get_by_label("Yes").click();
+    harness.run();
+    harness.get_by_label("Last choice: Yes");
+
+    open_dialog(&mut harness);
+    harness.get_by_label("No").click();
+    harness.run();
+    harness.get_by_label("Last choice: No");
+}
+
+// The modal blocks the widgets behind it: a click there dismisses the dialog instead
+#[test]
+fn background_clicks_are_ignored_while_open() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    open_dialog(&mut harness);
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("Counter: 0");
+    assert!(harness.query_by_label("Confirmation").is_none());
+    harness.get_by_label("Last choice: Dismissed");
+    assert_eq!(harness.state().counter, 0);
+    assert_eq!(
+        harness.state().last_confirmation,
+        Some(ConfirmOutcome::Dismissed)
+    );
+}
+
+#[test]
+fn focus_is_trapped_and_returned() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    open_dialog(&mut harness);
+    assert!(harness.get_by_label("Yes").is_focused());
+
+    // Tab cycles between the dialog's buttons and never reaches the page behind it
+    for expected in ["No", "Yes", "No"] {
+        harness.key_press(egui::Key::Tab);
+        harness.run();
+        assert!(
+            harness.get_by_label(expected).is_focused(),
+            "expected focus on {expected}"
+        );
+    }
+
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert!(harness.query_by_label("Confirmation").is_none());
+    assert!(harness.get_by_label("Show Dialog").is_focused());
+}
+
+#[test]
+fn escape_cancels_and_enter_confirms() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    open_dialog(&mut harness);
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert!(harness.query_by_label("Confirmation").is_none());
+    harness.get_by_label("Last choice: No");
+
+    open_dialog(&mut harness);
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+    assert!(harness.query_by_label("Confirmation").is_none());
+    harness.get_by_label("Last choice: Yes");
+}
+
+#[test]
+fn app_hook_receives_each_outcome() {
+    let outcomes = Rc::new(RefCell::new(Vec::new()));
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        let log = Rc::clone(&outcomes);
+        app.on_confirmation = Some(Box::new(move |outcome| log.borrow_mut().push(outcome)));
+    });
+    open_dialog(&mut harness);
+    harness.get_by_label("No").click();
+    harness.run();
+    open_dialog(&mut harness);
+    harness.get_by_label("Yes").click();
+    harness.run();
+    assert_eq!(
+        *outcomes.borrow(),
+        [ConfirmOutcome::Cancelled, ConfirmOutcome::Confirmed]
+    );
+}
+
+// The component works on its own, with custom button text
+#[test]
+fn standalone_dialog_reports_through_callback() {
+    let result = Rc::new(RefCell::new(None));
+    let mut harness = Harness::new(|ctx| {
+        let result = Rc::clone(&result);
+        ConfirmationDialog::new("Delete card?", "This cannot be undone.")
+            .confirm_text("Delete")
+            .cancel_text("Keep")
+            .on_outcome(move |outcome| *result.borrow_mut() = Some(outcome))
+            .show(ctx);
+    });
+    harness.get_by_label("This cannot be undone.");
+    harness.get_by_label("Keep").click();
+    harness.run();
+    assert_eq!(*result.borrow(), Some(ConfirmOutcome::Cancelled));
+}
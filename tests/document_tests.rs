@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{TempFile, Viewport, click_menu_item, demo_harness};
+use egui_kittest_demo::{Card, DemoApp, Document, DocumentError, FilePicker, FixedPathPicker};
+
+// Picker that behaves like a user pressing Cancel in the native dialog
+struct CancelPicker;
+
+impl FilePicker for CancelPicker {
+    fn pick_open(&mut self) -> Option<PathBuf> {
+        None
+    }
+
+    fn pick_save(&mut self, _current: Option<&Path>) -> Option<PathBuf> {
+        None
+    }
+}
+
+#[test]
+fn document_ron_round_trip() {
+    let document = Document {
+        name: "Zoë".into(),
+        age: 41,
+        counter: 7,
+        cards: vec![Card::new(9, "Only card", "Body")],
+        ..Document::default()
+    };
+    let text = document.to_ron().unwrap();
+    // Human-readable: field names appear verbatim
+    assert!(text.contains("name: \"Zoë\""), "{text}");
+    assert_eq!(Document::from_ron(&text).unwrap(), document);
+}
+
+#[test]
+fn invalid_and_future_documents_are_rejected() {
+    assert!(matches!(
+        Document::from_ron("(name: "),
+        Err(DocumentError::Format(_))
+    ));
+    assert!(matches!(
+        Document::from_ron("(version: 42)"),
+        Err(DocumentError::UnsupportedVersion(42))
+    ));
+    // Card ids key the cards' widgets and actions, so they must be unique
+    let document = Document {
+        cards: vec![Card::new(3, "A", ""), Card::new(3, "B", "")],
+        ..Document::default()
+    };
+    assert!(matches!(
+        Document::from_ron(&document.to_ron().unwrap()),
+        Err(DocumentError::DuplicateCardId(3))
+    ));
+}
+
+#[test]
+fn save_then_open_through_file_menu() {
+    let document = TempFile::new("save_then_open");
+    let path = &document.path;
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.file_picker = Box::new(FixedPathPicker::new(path));
+        // Save is only available for a valid form
+        app.name = "Ada".into();
+        app.age = 36;
+    });
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("Document: Untitled*");
+
+    click_menu_item(&mut harness, "File", "Save");
+    harness.get_by_label("Document: save_then_open.ron");
+
+    // Change state, then reopen the saved file to get it back
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("Counter: 2");
+    click_menu_item(&mut harness, "File", "Open");
+    // Unsaved changes are guarded; discard them to reopen the file
+    harness.get_by_label("Discard").click();
+    harness.run();
+    harness.get_by_label("Counter: 1");
+    harness.get_by_label("Document: save_then_open.ron");
+    assert!(!harness.state().is_dirty());
+    assert_eq!(Document::read(path).unwrap().counter, 1);
+}
+
+#[test]
+fn new_resets_state_and_dirty_flag() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 5;
+        app.name = "Temp".into();
+        app.cards_mut().clear();
+    });
+    assert!(harness.state().is_dirty());
+    assert!(harness.state().title().starts_with("Untitled*"));
+    click_menu_item(&mut harness, "File", "New");
+    harness.get_by_label("Discard").click();
+    harness.run();
+    harness.get_by_label("Counter: 0");
+    harness.get_by_label("Card 1");
+    harness.get_by_label("Document: Untitled");
+    assert!(!harness.state().is_dirty());
+    assert_eq!(harness.state().cards(), Card::demo_set());
+}
+
+#[test]
+fn cancelled_picker_changes_nothing() {
+    let mut app = DemoApp::new();
+    app.file_picker = Box::new(CancelPicker);
+    app.name = "Ada".into();
+    app.age = 36;
+    app.counter = 3;
+    app.save_document();
+    app.open_document();
+    assert_eq!(app.counter, 3);
+    assert!(app.document_path.is_none());
+    assert!(app.is_dirty());
+    assert!(app.file_error.is_none());
+}
+
+#[test]
+fn open_error_is_reported() {
+    let document = TempFile::new("missing");
+    let path = &document.path;
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.file_picker = Box::new(FixedPathPicker::new(path));
+    });
+    click_menu_item(&mut harness, "File", "Open");
+    harness.get_by_label_contains("Could not open");
+}
@@ -0,0 +1,119 @@
+use egui::accesskit::Role;
+use egui_kittest::kittest::{NodeT, Queryable};
+use egui_kittest_demo::testing::demo_harness;
+use egui_kittest_demo::{Card, FilterState, SortOrder};
+
+fn titles(cards: &[&Card]) -> Vec<String> {
+    cards.iter().map(|card| card.title.clone()).collect()
+}
+
+#[test]
+fn tags_narrow_with_and_semantics() {
+    let cards = Card::demo_set();
+    let mut filters = FilterState::default();
+    assert_eq!(filters.apply(&cards).len(), 6);
+
+    filters.toggle_tag("Layout");
+    assert_eq!(
+        titles(&filters.apply(&cards)),
+        ["Card 1", "Card 3", "Card 6"]
+    );
+    filters.toggle_tag("Testing");
+    assert_eq!(titles(&filters.apply(&cards)), ["Card 6"]);
+
+    // Toggling again removes the tag
+    filters.toggle_tag("Testing");
+    filters.toggle_tag("Layout");
+    assert!(filters.tags.is_empty());
+}
+
+#[test]
+fn search_is_case_insensitive_over_title_and_body() {
+    let cards = vec![
+        Card::new(1, "Alpha", "first body"),
+        Card::new(2, "Beta", "mentions ALPHA in the body"),
+        Card::new(3, "Gamma", "nothing"),
+    ];
+    let filters = FilterState {
+        search: "  alpha ".into(),
+        ..FilterState::default()
+    };
+    assert_eq!(titles(&filters.apply(&cards)), ["Alpha", "Beta"]);
+}
+
+#[test]
+fn sort_orders() {
+    let cards = vec![
+        Card::new(1, "beta", ""),
+        Card::new(2, "Alpha", ""),
+        Card::new(3, "gamma", ""),
+    ];
+    let mut filters = FilterState::default();
+    assert_eq!(titles(&filters.apply(&cards)), ["beta", "Alpha", "gamma"]);
+    filters.sort = SortOrder::TitleAscending;
+    assert_eq!(titles(&filters.apply(&cards)), ["Alpha", "beta", "gamma"]);
+    filters.sort = SortOrder::TitleDescending;
+    assert_eq!(titles(&filters.apply(&cards)), ["gamma", "beta", "Alpha"]);
+}
+
+#[test]
+fn tag_checkbox_filters_the_grid() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.get_by_label("Showing 6 of 6 cards");
+
+    harness.get_by_label("Accessibility").click();
+    harness.run();
+    harness.get_by_label("Showing 2 of 6 cards");
+    harness.get_by_label("Card 3");
+    harness.get_by_label("Card 5");
+    assert!(harness.query_by_label("Card 1").is_none());
+
+    harness.get_by_label("Clear filters").click();
+    harness.run();
+    harness.get_by_label("Showing 6 of 6 cards");
+}
+
+#[test]
+fn search_box_filters_the_grid() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    let search = harness.get_by_role(Role::SearchInput);
+    search.focus();
+    search.type_text("card 4");
+    harness.run();
+    harness.get_by_label("Showing 1 of 6 cards");
+    harness.get_by_label("Card 4");
+}
+
+#[test]
+fn sort_combo_reorders_cards() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.get_by_role(Role::ComboBox).click();
+    harness.run();
+    harness.get_by_label("Title Z–A").click();
+    harness.run();
+
+    // Card 6 now leads the first row
+    let first = harness.get_by_label("Card 6").rect();
+    let second = harness.get_by_label("Card 5").rect();
+    assert!(first.min.x < second.min.x);
+}
+
+// Filters live on DemoApp, so switching between side+central and stacked keeps them
+#[test]
+fn filters_survive_layout_switch() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.get_by_label("Layout: Side+Central");
+    harness.get_by_label("Testing").click();
+    harness.run();
+    harness.get_by_label("Showing 3 of 6 cards");
+
+    harness.set_size(egui::vec2(360.0, 600.0));
+    harness.run();
+    harness.get_by_label("Layout: Stacked");
+    harness.get_by_label("Showing 3 of 6 cards");
+    let testing = harness.get_by_label("Testing");
+    assert_eq!(
+        testing.accesskit_node().toggled(),
+        Some(egui::accesskit::Toggled::True)
+    );
+}
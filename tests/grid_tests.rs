@@ -0,0 +1,119 @@
+use egui_kittest::{
+    Harness,
+    kittest::{NodeT, Queryable},
+};
+use egui_kittest_demo::testing::demo_harness;
+use egui_kittest_demo::{Card, DemoApp, StaticCards};
+
+fn cards(count: u64) -> Vec<Card> {
+    (1..=count)
+        .map(|n| Card::new(n, format!("Card {n}"), "Generated card body"))
+        .collect()
+}
+
+fn grid_harness(count: u64, size: egui::Vec2) -> Harness<'static, DemoApp> {
+    let mut harness = demo_harness(size, |app| {
+        app.load_cards(&mut StaticCards(cards(count))).unwrap();
+    });
+    harness.run();
+    harness
+}
+
+// Frame cost is driven by the viewport, so it must not grow with the total
+#[test]
+fn ten_thousand_cards_do_the_same_work_per_frame_as_a_hundred() {
+    let size = egui::vec2(820.0, 600.0);
+    let mut small = grid_harness(100, size);
+    small.get_by_label("Showing 100 of 100 cards");
+    let mut large = grid_harness(10_000, size);
+    large.get_by_label("Showing 10000 of 10000 cards");
+    small.run_steps(5);
+    large.run_steps(5);
+
+    let stats = &large.state().card_grid.stats;
+    assert_eq!(stats.rows, 5_000);
+    assert!(stats.rendered_cards > 0);
+    assert!(
+        stats.rendered_cards <= 20,
+        "laid out {} of 10000 cards",
+        stats.rendered_cards
+    );
+    let small_stats = &small.state().card_grid.stats;
+    assert_eq!(stats.rendered_cards, small_stats.rendered_cards);
+    assert_eq!(stats.placeholders, small_stats.placeholders);
+
+    // Idle frames reuse the filtered order instead of filtering and sorting again
+    assert_eq!(small.state().filtered_cards.passes, 1);
+    assert_eq!(large.state().filtered_cards.passes, 1);
+
+    let accesskit_nodes =
+        |harness: &Harness<'_, DemoApp>| harness.root().children_recursive().count();
+    assert_eq!(accesskit_nodes(&large), accesskit_nodes(&small));
+}
+
+#[test]
+fn column_rules_still_apply() {
+    for (width, columns, rows) in [(360.0, 1, 10_000), (820.0, 2, 5_000), (1280.0, 3, 3_334)] {
+        let harness = grid_harness(10_000, egui::vec2(width, 720.0));
+        harness.get_by_label(&format!("Columns: {columns}"));
+        assert_eq!(
+            harness.state().card_grid.stats.rows,
+            rows,
+            "at {width} wide"
+        );
+    }
+}
+
+#[test]
+fn offscreen_cards_scroll_into_view_via_accesskit() {
+    let mut harness = grid_harness(10_000, egui::vec2(820.0, 600.0));
+    // Off-screen cards are not laid out, so their controls don't exist yet
+    assert!(harness.query_by_label("Details for Card 40").is_none());
+
+    harness.get_by_label("Card 40").scroll_to_me();
+    harness.run();
+    harness.get_by_label("Details for Card 40");
+    let stats = &harness.state().card_grid.stats;
+    assert!(stats.rendered_rows.contains(&19), "{stats:?}");
+    // A screenful of measured rows plus overscan, not the whole grid
+    assert!(stats.rendered_cards <= 30, "{stats:?}");
+}
+
+// Placeholders only cover rows near the viewport; scroll_to_card reaches the rest
+#[test]
+fn far_cards_are_reached_with_scroll_to_card() {
+    let mut harness = grid_harness(10_000, egui::vec2(820.0, 600.0));
+    assert!(harness.query_by_label("Card 9999").is_none());
+
+    harness.state_mut().card_grid.scroll_to_card(9999);
+    harness.run();
+    let card = harness.get_by_label("Card 9999");
+    let viewport = harness.ctx.screen_rect();
+    assert!(viewport.contains_rect(card.rect()), "{:?}", card.rect());
+    harness.get_by_label("Details for Card 9999");
+    let stats = &harness.state().card_grid.stats;
+    assert_eq!(stats.rendered_rows.end, stats.rows, "{stats:?}");
+}
+
+// Tab lands on the overscan row below the viewport, which must scroll into view so
+// the row after it gets laid out too
+#[test]
+fn tab_walks_past_the_first_screen_of_cards() {
+    let mut harness = grid_harness(100, egui::vec2(820.0, 600.0));
+    assert!(harness.query_by_label("Action for Card 21").is_none());
+    harness.get_by_label("Action for Card 1").focus();
+    harness.run();
+
+    // Three controls per card
+    for _ in 0..3 * 20 {
+        harness.key_press(egui::Key::Tab);
+        harness.run();
+    }
+    assert!(
+        harness
+            .get_by_label("Action for Card 21")
+            .accesskit_node()
+            .is_focused()
+    );
+    assert!(harness.state().card_grid.stats.rendered_rows.start > 0);
+}
@@ -0,0 +1,240 @@
+use egui::accesskit::Role;
+use egui::{Key, Modifiers};
+use egui_kittest::{
+    Harness,
+    kittest::{NodeT, Queryable},
+};
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+use egui_kittest_demo::{DemoApp, Edit, History, ScalingMode};
+
+fn counter(before: i32, after: i32) -> Edit {
+    Edit::Counter { before, after }
+}
+
+fn name(before: &str, after: &str) -> Edit {
+    Edit::Name {
+        before: before.into(),
+        after: after.into(),
+    }
+}
+
+fn undo(harness: &mut Harness<'_, DemoApp>) {
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::Z);
+    harness.run();
+}
+
+fn redo(harness: &mut Harness<'_, DemoApp>) {
+    harness.key_press_modifiers(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z);
+    harness.run();
+}
+
+#[test]
+fn consecutive_name_edits_coalesce_until_sealed() {
+    let mut history = History::default();
+    history.push(name("", "A"));
+    history.push(name("A", "Ad"));
+    history.push(name("Ad", "Ada"));
+    assert_eq!(history.undo_len(), 1);
+
+    history.seal();
+    history.push(name("Ada", "Adam"));
+    assert_eq!(history.undo_len(), 2);
+
+    // A different kind of edit ends the group as well
+    history.push(counter(0, 1));
+    history.push(name("Adam", "Ada"));
+    assert_eq!(history.undo_len(), 4);
+}
+
+#[test]
+fn depth_limit_drops_the_oldest_steps() {
+    let mut app = DemoApp::new();
+    app.history = History::with_limit(3);
+    for n in 0..5 {
+        app.execute(counter(n, n + 1));
+    }
+    assert_eq!(app.history.undo_len(), 3);
+    while app.undo() {}
+    assert_eq!(app.counter, 2);
+
+    app.history.set_limit(1);
+    assert_eq!(app.history.undo_len(), 0);
+    assert_eq!(app.history.limit(), 1);
+}
+
+#[test]
+fn new_edit_clears_redo() {
+    let mut app = DemoApp::new();
+    app.execute(counter(0, 1));
+    app.execute(counter(1, 2));
+    assert!(app.undo());
+    assert!(app.history.can_redo());
+    app.execute(counter(1, 5));
+    assert!(!app.history.can_redo());
+    assert!(!app.redo());
+    assert_eq!(app.counter, 5);
+}
+
+// Replay a mixed sequence through the UI, then undo every step and redo it again
+#[test]
+fn replayed_session_undoes_completely() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("Increment").click();
+    harness.run();
+
+    let input = harness.get_by_role(Role::TextInput);
+    input.focus();
+    input.type_text("Ada");
+    harness.run();
+    // Leaving the field closes the coalesced name step
+    harness.get_by_label("Decrement").click();
+    harness.run();
+
+    harness.get_by_label("View").click();
+    harness.run();
+    harness.get_by_label("Style-based").click();
+    harness.run();
+    harness.key_press(Key::Escape);
+    harness.run();
+
+    harness.get_by_label("Counter: 1");
+    harness.get_by_label_contains("Hello, Ada!");
+    harness.get_by_label("Scaling mode: Style");
+
+    // Mode, decrement, name (one step for three keystrokes), two increments
+    for _ in 0..5 {
+        undo(&mut harness);
+    }
+    harness.get_by_label("Counter: 0");
+    // An empty name is invalid, so there is no greeting
+    assert!(harness.query_by_label_contains("Hello").is_none());
+    harness.get_by_label("Scaling mode: Zoom");
+    assert_eq!(
+        harness.get_by_role(Role::TextInput).value().as_deref(),
+        Some("")
+    );
+
+    for _ in 0..5 {
+        redo(&mut harness);
+    }
+    harness.get_by_label("Counter: 1");
+    harness.get_by_label_contains("Hello, Ada!");
+    harness.get_by_label("Scaling mode: Style");
+    assert_eq!(harness.state().history.undo_len(), 5);
+    while harness.state_mut().undo() {}
+    assert!(!harness.state().is_dirty());
+    assert_eq!(harness.state().scaling_mode, ScalingMode::Zoom);
+}
+
+#[test]
+fn edit_menu_entries_follow_the_stacks() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    harness.get_by_label("Edit").click();
+    harness.run();
+    assert!(
+        harness
+            .get_by_label_contains("Undo")
+            .accesskit_node()
+            .is_disabled()
+    );
+    assert!(
+        harness
+            .get_by_label_contains("Redo")
+            .accesskit_node()
+            .is_disabled()
+    );
+    harness.key_press(Key::Escape);
+    harness.run();
+
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("Edit").click();
+    harness.run();
+    harness.get_by_label_contains("Undo").click();
+    harness.run();
+    harness.get_by_label("Counter: 0");
+
+    harness.get_by_label("Edit").click();
+    harness.run();
+    harness.get_by_label_contains("Redo").click();
+    harness.run();
+    harness.get_by_label("Counter: 1");
+}
+
+#[test]
+fn card_menu_actions_are_undoable() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.get_by_label("More for Card 2").click();
+    harness.run();
+    harness.get_by_label("Delete").click();
+    harness.run();
+    assert!(harness.query_by_label("Card 2").is_none());
+    undo(&mut harness);
+    harness.get_by_label("Card 2");
+    assert!(!harness.state().is_dirty());
+}
+
+#[test]
+fn card_edits_record_only_the_card_they_touch() {
+    let mut app = DemoApp::new();
+    let ids = |app: &DemoApp| app.cards().iter().map(|card| card.id).collect::<Vec<_>>();
+    let original = ids(&app);
+
+    let duplicate = app.duplicate_card(2).unwrap();
+    let Edit::Insert { index: 2, card } = &duplicate else {
+        panic!("duplicating records an insert after the original: {duplicate:?}");
+    };
+    assert_eq!(&app.cards()[2], card);
+    let delete = app.delete_card(1).unwrap();
+    let Edit::Remove { index: 0, card } = &delete else {
+        panic!("deleting records the removed card: {delete:?}");
+    };
+    assert_eq!(card.id, 1);
+    let pin = app.toggle_pin(3).unwrap();
+    assert_eq!(pin, Edit::TogglePin { id: 3 });
+    assert_eq!(app.toggle_pin(99), None);
+
+    let edited = app.cards().to_vec();
+    let edits = [duplicate, delete, pin];
+    for edit in edits.iter().rev() {
+        edit.revert(&mut app);
+    }
+    assert_eq!(ids(&app), original);
+    for edit in &edits {
+        edit.apply(&mut app);
+    }
+    assert_eq!(app.cards(), edited);
+}
+
+#[test]
+fn card_actions_and_details_record_nothing() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.get_by_label("Action for Card 2").click();
+    harness.run();
+    harness.get_by_label("Details for Card 2").click();
+    harness.run();
+    assert_eq!(harness.state().history.undo_len(), 0);
+}
+
+#[test]
+fn browsing_the_view_menu_records_nothing() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    harness.get_by_label("Increment").click();
+    harness.run();
+    undo(&mut harness);
+
+    harness.get_by_label("View").click();
+    harness.run();
+    // Picking the values that are already selected changes nothing
+    harness.get_by_label("Zoom-based").click();
+    harness.run();
+    harness.get_by_label("View").click();
+    harness.run();
+    harness.get_by_label("System").click();
+    harness.run();
+
+    assert_eq!(harness.state().history.undo_len(), 0);
+    assert!(harness.state().history.can_redo());
+}
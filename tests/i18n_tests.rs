@@ -0,0 +1,113 @@
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{Viewport, demo_harness, fixture};
+use egui_kittest_demo::{FixedPathPicker, FormValidator, Locale, PluralCategory};
+
+#[test]
+fn plural_forms_follow_the_count() {
+    let en = Locale::En;
+    assert_eq!(en.plural_category(1), PluralCategory::One);
+    assert_eq!(en.plural_category(0), PluralCategory::Other);
+    assert_eq!(
+        en.format_plural("greeting", 1, &[("name", &"Ada")]),
+        "Hello, Ada! You are 1 year old."
+    );
+    assert_eq!(
+        en.format_plural("greeting", 42, &[("name", &"Ada")]),
+        "Hello, Ada! You are 42 years old."
+    );
+    assert_eq!(
+        Locale::De.format_plural("greeting", 1, &[("name", &"Ada")]),
+        "Hallo, Ada! Du bist 1 Jahr alt."
+    );
+    assert_eq!(
+        Locale::De.format_plural("cards.showing", 3, &[("shown", &2)]),
+        "2 von 3 Karten angezeigt"
+    );
+}
+
+#[test]
+fn missing_messages_fall_back_to_english() {
+    assert!(
+        Locale::De.missing_keys().is_empty(),
+        "{:?}",
+        Locale::De.missing_keys()
+    );
+    assert_eq!(Locale::De.text("menu.file"), "Datei");
+    // Keys without a message in any catalog are shown as they are
+    assert_eq!(Locale::De.text("Nickname"), "Nickname");
+    assert_eq!(
+        Locale::De.format("status.theme", &[]),
+        "Design: {theme}",
+        "unknown placeholders are kept"
+    );
+
+    let errors = FormValidator::default().validate_in(Locale::De, "", 121);
+    assert_eq!(errors.name.as_deref(), Some("Name ist erforderlich"));
+    assert_eq!(
+        errors.age.as_deref(),
+        Some("Alter muss zwischen 0 und 120 liegen")
+    );
+}
+
+#[test]
+fn pinned_locale_translates_the_ui() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.locale = Locale::De;
+        app.name = "Ada".into();
+        app.age = 1;
+    });
+    harness.run();
+
+    harness.get_by_label("Datei");
+    harness.get_by_label("Zähler: 0");
+    harness.get_by_label("Hallo, Ada! Du bist 1 Jahr alt.");
+    harness.get_by_label("Dokument: Unbenannt*");
+    harness.get_by_label("Details für Card 1");
+
+    harness.get_by_label("Erhöhen").click();
+    harness.run();
+    harness.get_by_label("Zähler: 1");
+}
+
+// File and theme errors reach the status area in the UI language
+#[test]
+fn file_and_theme_errors_are_translated() {
+    let mut harness = demo_harness(Viewport::Medium, |app| app.locale = Locale::De);
+    let app = harness.state_mut();
+
+    app.load_cards_file(fixture("duplicate_ids.ron"));
+    let error = app.file_error.clone().unwrap();
+    assert!(
+        error.ends_with("konnten nicht geladen werden: doppelte Karten-ID 1"),
+        "{error}"
+    );
+
+    app.file_picker = Box::new(FixedPathPicker::new(fixture("missing.ron")));
+    app.open_document();
+    let error = app.file_error.clone().unwrap();
+    assert!(
+        error.ends_with("konnte nicht geöffnet werden: Datei nicht gefunden"),
+        "{error}"
+    );
+
+    let _ = app.load_theme_file(fixture("themes/bad_color.ron"));
+    harness.run();
+    harness.get_by_label_contains("bad_color.ron: ungültige Designdatei: 3:");
+    harness.get_by_label_contains("Datei nicht gefunden");
+}
+
+#[test]
+fn language_menu_switches_the_locale() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.run();
+    harness.get_by_label("Counter: 0");
+
+    harness.get_by_label("View").click();
+    harness.run();
+    harness.get_by_label("Language");
+    harness.get_by_label("Deutsch").click();
+    harness.run();
+    harness.get_by_label("Zähler: 0");
+    harness.get_by_label("Ansicht");
+    assert_eq!(harness.state().locale, Locale::De);
+}
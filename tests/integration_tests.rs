@@ -1,6 +1,6 @@
-use eframe::App;
 use egui::accesskit::Role;
 use egui_kittest::{Harness, kittest::Queryable};
+use egui_kittest_demo::testing::{Viewport, demo_harness};
 
 #[test]
 fn test_basic_ui_elements() {
@@ -188,15 +188,8 @@ fn test_menu_interaction() {
 
 #[test]
 fn test_semantic_layout_indicators() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-
     // Narrow: expect stacked layout and 1 column
-    let mut harness = Harness::builder()
-        .with_size(egui::vec2(360.0, 500.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(360.0, 500.0), |_| {});
     harness.get_by_label("Layout: Stacked");
     // Columns label should be visible and equal to 1
     harness.get_by_label("Columns: 1");
@@ -215,15 +208,8 @@ fn test_semantic_layout_indicators() {
 
 #[test]
 fn test_adaptive_scaling_labels_and_progression() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-
     // Small width
-    let mut harness = Harness::builder()
-        .with_size(egui::vec2(360.0, 500.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(360.0, 500.0), |_| {});
     let small_scale = harness.get_by_label_contains("Scale: ");
     let small_text = small_scale
         .value()
@@ -258,7 +244,10 @@ fn test_adaptive_scaling_labels_and_progression() {
     let medium = parse_percent(&medium_text);
     let wide = parse_percent(&wide_text);
 
-    assert!(small <= medium && medium <= wide, "scale should be non-decreasing with width: {small} <= {medium} <= {wide}");
+    assert!(
+        small <= medium && medium <= wide,
+        "scale should be non-decreasing with width: {small} <= {medium} <= {wide}"
+    );
 
     // Also validate scale bucket labels exist at each size
     harness.get_by_label_contains("Scale bucket: ");
@@ -266,13 +255,7 @@ fn test_adaptive_scaling_labels_and_progression() {
 
 #[test]
 fn test_scale_bucket_across_breakpoints() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-    let mut harness = Harness::builder()
-        .with_size(egui::vec2(360.0, 500.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(360.0, 500.0), |_| {});
     harness.get_by_label("Scale bucket: Small");
 
     harness.set_size(egui::vec2(820.0, 600.0));
@@ -286,15 +269,8 @@ fn test_scale_bucket_across_breakpoints() {
 
 #[test]
 fn test_text_scaling_affects_geometry() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-
     // Small window
-    let mut harness = Harness::builder()
-        .with_size(egui::vec2(360.0, 320.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(360.0, 320.0), |_| {});
     let heading_small = harness.get_by_label("egui_kittest Demo App");
     let h_small = heading_small.rect().height();
 
@@ -305,19 +281,15 @@ fn test_text_scaling_affects_geometry() {
     let h_wide = heading_wide.rect().height();
 
     // Expect some increase due to higher zoom at wide sizes
-    assert!(h_wide >= h_small + 1.0, "heading height should increase with scale: small={h_small}, wide={h_wide}");
+    assert!(
+        h_wide >= h_small + 1.0,
+        "heading height should increase with scale: small={h_small}, wide={h_wide}"
+    );
 }
 
 #[test]
 fn test_all_cards_visible_via_scroll_narrow() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-
-    let harness = Harness::builder()
-        .with_size(egui::vec2(320.0, 220.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let harness = demo_harness(egui::vec2(320.0, 220.0), |_| {});
 
     // All card headings should be reachable even at narrow size due to ScrollArea
     for i in 1..=6 {
@@ -328,31 +300,20 @@ fn test_all_cards_visible_via_scroll_narrow() {
 // Stacking decision should consider central width (right panel) when the left panel is made very wide
 #[test]
 fn test_stacking_considers_central_width_when_left_is_wide() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-    // Simulate a wide window but with a very wide left panel from a previous frame
-    // This should cause predicted central width to be small and trigger stacking
-    // Use physical px prediction; we don't know ppp here, so choose a large value
-    app.left_panel_width_px = Some(1000.0);
-    let harness = Harness::builder()
-        .with_size(egui::vec2(900.0, 600.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(900.0, 600.0), |_| {});
+    harness.get_by_label("Layout: Side+Central");
 
+    // The left panel measured very wide on the last frame (physical px), which leaves
+    // too little predicted central width and triggers stacking
+    harness.state_mut().left_panel_width_px = Some(1000.0);
+    harness.run();
     harness.get_by_label("Layout: Stacked");
 }
 
 // Scaling: toggling the View -> Style-based mode should reflect in the semantic label
 #[test]
 fn test_view_menu_scaling_mode_toggle() {
-    let mut app = egui_kittest_demo::DemoApp::new();
-    let mut harness = Harness::builder()
-        .with_size(egui::vec2(820.0, 600.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
 
     // Open the View menu and toggle to Style-based
     harness.get_by_label("View").click();
@@ -370,26 +331,14 @@ fn test_view_menu_scaling_mode_toggle() {
 #[test]
 fn test_columns_and_bucket_stable_across_modes() {
     // First, check in default (Zoom) mode at wide width
-    let mut app = egui_kittest_demo::DemoApp::new();
-    {
-        let harness = Harness::builder()
-        .with_size(egui::vec2(1280.0, 720.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
-        harness.get_by_label("Columns: 3");
-        harness.get_by_label("Scale bucket: Large");
-    }
+    let mut harness = demo_harness(Viewport::Wide, |_| {});
+    harness.get_by_label("Columns: 3");
+    harness.get_by_label("Scale bucket: Large");
 
-    // Now set Style mode directly and rebuild to avoid menu access flakiness
-    app.scaling_mode = egui_kittest_demo::ScalingMode::Style;
-    let harness2 = Harness::builder()
-        .with_size(egui::vec2(1280.0, 720.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
-    harness2.get_by_label("Columns: 3");
-    harness2.get_by_label("Scale bucket: Large");
+    // Now set Style mode directly between frames to avoid menu access flakiness
+    harness.state_mut().scaling_mode = egui_kittest_demo::ScalingMode::Style;
+    harness.run();
+    harness.get_by_label("Scaling mode: Style");
+    harness.get_by_label("Columns: 3");
+    harness.get_by_label("Scale bucket: Large");
 }
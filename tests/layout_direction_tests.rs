@@ -0,0 +1,93 @@
+use egui::Vec2;
+use egui::accesskit::Role;
+use egui_kittest::kittest::{Queryable, by};
+use egui_kittest_demo::LayoutDirection;
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+
+// Mirror of `wide_grid_geometry_columns_order`: the first row runs right to left
+#[test]
+fn rtl_grid_geometry_columns_order() {
+    let harness = demo_harness(Viewport::Wide, |app| {
+        app.layout_direction = LayoutDirection::RightToLeft;
+    });
+    harness.get_by_label("Columns: 3");
+
+    let r1 = harness.get_by_label("Card 1").rect();
+    let r2 = harness.get_by_label("Card 2").rect();
+    let r3 = harness.get_by_label("Card 3").rect();
+    assert!(
+        r1.min.x > r2.min.x && r2.min.x > r3.min.x,
+        "Cards 1,2,3 should be laid out right-to-left in the first row: {r1:?} {r2:?} {r3:?}"
+    );
+    // Still one row
+    assert!((r1.min.y - r3.min.y).abs() < 1.0);
+}
+
+#[test]
+fn rtl_puts_the_filters_panel_on_the_right() {
+    let harness = demo_harness(Viewport::Wide, |app| {
+        app.layout_direction = LayoutDirection::RightToLeft;
+    });
+    harness.get_by_label("Layout: Side+Central");
+
+    let search = harness.get_by_label("Search cards").rect();
+    let heading = harness.get_by_label("egui_kittest Demo App").rect();
+    assert!(
+        search.min.x > heading.max.x,
+        "filters {search:?} should be right of the main content {heading:?}"
+    );
+}
+
+#[test]
+fn rtl_flips_form_and_counter_rows() {
+    for size in [Viewport::Wide.size(), Vec2::new(360.0, 900.0)] {
+        let harness = demo_harness(size, |app| {
+            app.layout_direction = LayoutDirection::RightToLeft;
+        });
+
+        // The text labels the input, so look the label node up by role and value
+        let name_label = harness.get(by().role(Role::Label).value("Name")).rect();
+        let name_input = harness.get_by_label("Name").rect();
+        assert!(
+            name_label.min.x >= name_input.max.x,
+            "at {size:?}, Name: {name_label:?} should follow its input {name_input:?} from the right"
+        );
+        let age_label = harness.get(by().role(Role::Label).value("Age")).rect();
+        let age_input = harness.get_by_label("Age").rect();
+        assert!(age_label.min.x >= age_input.max.x, "at {size:?}");
+
+        let increment = harness.get_by_label("Increment").rect();
+        let decrement = harness.get_by_label("Decrement").rect();
+        let counter = harness.get_by_label("Counter: 0").rect();
+        assert!(
+            increment.min.x > decrement.max.x && decrement.min.x > counter.max.x,
+            "at {size:?}, the counter row should run right to left"
+        );
+    }
+}
+
+#[test]
+fn layout_direction_menu_mirrors_the_shell() {
+    // At zoom 1.0, so clicks land where the menu is drawn
+    let mut harness = demo_harness(Vec2::new(820.0, 900.0), |_| {});
+    harness.run();
+    harness.get_by_label("Layout: Side+Central");
+    let search = harness.get_by_label("Search cards").rect();
+    let heading = harness.get_by_label("egui_kittest Demo App").rect();
+    assert!(search.max.x < heading.min.x);
+
+    harness.get_by_label("View").click();
+    harness.run();
+    harness.get_by_label("Right to left").click();
+    harness.run();
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+
+    let search = harness.get_by_label("Search cards").rect();
+    let heading = harness.get_by_label("egui_kittest Demo App").rect();
+    assert!(search.min.x > heading.max.x);
+    assert_eq!(
+        harness.state().layout_direction,
+        LayoutDirection::RightToLeft
+    );
+}
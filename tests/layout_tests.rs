@@ -0,0 +1,233 @@
+use egui_kittest::{Harness, kittest::Queryable};
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+use egui_kittest_demo::{
+    Breakpoints, DemoApp, FluidScale, Hysteresis, LayoutDecision, LayoutMode, ScaleBucket,
+    ScalingMode, SizeClass,
+};
+
+// Representative widths from the docs: 360 stacked/1 col, 820 side+central/2 cols, 1280 3 cols
+#[test]
+fn default_breakpoints_at_representative_widths() {
+    let bp = Breakpoints::default();
+
+    let narrow = LayoutDecision::compute(&bp, 360.0, 1.0, None);
+    assert_eq!(narrow.mode, LayoutMode::Stacked);
+    assert_eq!(narrow.columns, 1);
+    assert_eq!(narrow.bucket, ScaleBucket::Small);
+
+    let medium = LayoutDecision::compute(&bp, 820.0, 1.0, None);
+    assert_eq!(medium.mode, LayoutMode::SideCentral);
+    assert_eq!(medium.columns, 2);
+    assert_eq!(medium.bucket, ScaleBucket::Medium);
+
+    let wide = LayoutDecision::compute(&bp, 1280.0, 1.0, None);
+    assert_eq!(wide.mode, LayoutMode::SideCentral);
+    assert_eq!(wide.columns, 3);
+    assert_eq!(wide.bucket, ScaleBucket::Large);
+    assert_eq!(wide.size_class, SizeClass::ExtraLarge);
+}
+
+// Thresholds are inclusive on the upper side: 600 is medium, 900 is large
+#[test]
+fn thresholds_are_inclusive_upwards() {
+    let bp = Breakpoints::default();
+    assert_eq!(bp.size_class(599.0), SizeClass::Small);
+    assert_eq!(bp.size_class(600.0), SizeClass::Medium);
+    assert_eq!(bp.size_class(899.0), SizeClass::Medium);
+    assert_eq!(bp.size_class(900.0), SizeClass::Large);
+    assert_eq!(bp.size_class(1279.0), SizeClass::Large);
+    assert_eq!(bp.size_class(1280.0), SizeClass::ExtraLarge);
+}
+
+// Physical width drives the decision, so the same physical width gives the same result at 2x DPI
+#[test]
+fn decision_is_stable_across_pixels_per_point() {
+    let bp = Breakpoints::default();
+    let at_1x = LayoutDecision::compute(&bp, 1280.0, 1.0, None);
+    let at_2x = LayoutDecision::compute(&bp, 1280.0, 2.0, None);
+    assert_eq!(at_1x.columns, at_2x.columns);
+    assert_eq!(at_1x.bucket, at_2x.bucket);
+}
+
+// A wide left panel leaves too little central width and forces stacking
+#[test]
+fn wide_left_panel_forces_stacking() {
+    let bp = Breakpoints::default();
+    let decision = LayoutDecision::compute(&bp, 900.0, 1.0, Some(1000.0));
+    assert!(decision.is_stacked());
+    // Columns still follow the window width
+    assert_eq!(decision.columns, 3);
+}
+
+#[test]
+fn scale_follows_mode_tables() {
+    let bp = Breakpoints::default();
+    let small = LayoutDecision::compute(&bp, 360.0, 1.0, None);
+    let wide = LayoutDecision::compute(&bp, 1280.0, 1.0, None);
+    assert_eq!(small.scale(ScalingMode::Zoom), 0.85);
+    assert_eq!(wide.scale(ScalingMode::Zoom), 1.50);
+    assert_eq!(small.scale(ScalingMode::Style), 0.95);
+    assert_eq!(wide.scale(ScalingMode::Style), 1.60);
+}
+
+// Custom thresholds on DemoApp change the rendered layout
+#[test]
+fn custom_breakpoints_on_demo_app() {
+    let harness = demo_harness(Viewport::Wide, |app| {
+        app.breakpoints = Breakpoints {
+            large_px: 1500.0,
+            extra_large_px: 2000.0,
+            ..Breakpoints::default()
+        };
+    });
+    harness.get_by_label("Layout: Side+Central");
+    harness.get_by_label("Columns: 2");
+    harness.get_by_label("Scale bucket: Medium");
+    harness.get_by_label("Scale: 100%");
+}
+
+// Small moves around a threshold keep the previous decision until the half-band is crossed
+#[test]
+fn hysteresis_holds_decision_inside_band() {
+    let bp = Breakpoints::default();
+    let large = LayoutDecision::compute(&bp, 920.0, 1.0, None);
+    assert_eq!(large.columns, 3);
+
+    let mut previous = large;
+    for width in [895.0, 905.0, 890.0, 910.0, 886.0] {
+        let next = LayoutDecision::compute_with_previous(&bp, width, 1.0, None, Some(&previous));
+        assert_eq!(next, large, "decision flipped at {width}px");
+        previous = next;
+    }
+
+    // Well past the band the decision does change
+    let medium = LayoutDecision::compute_with_previous(&bp, 880.0, 1.0, None, Some(&previous));
+    assert_eq!(medium.columns, 2);
+    assert_eq!(medium.bucket, ScaleBucket::Medium);
+    // ...and coming back needs to clear the upper half of the band
+    let still_medium = LayoutDecision::compute_with_previous(&bp, 910.0, 1.0, None, Some(&medium));
+    assert_eq!(still_medium.columns, 2);
+}
+
+// Central width hovering around central_min_points must not toggle stacking
+#[test]
+fn hysteresis_on_central_width_limit() {
+    let bp = Breakpoints::default();
+    let side = LayoutDecision::compute(&bp, 1000.0, 1.0, Some(530.0));
+    assert_eq!(side.mode, LayoutMode::SideCentral);
+
+    // Left panel grows so the central area is 455 pt, just under the 460 pt limit
+    let next = LayoutDecision::compute_with_previous(&bp, 1000.0, 1.0, Some(545.0), Some(&side));
+    assert_eq!(next.mode, LayoutMode::SideCentral);
+
+    // Past the band it stacks, and a 465 pt central area does not unstack it again
+    let stacked = LayoutDecision::compute_with_previous(&bp, 1000.0, 1.0, Some(560.0), Some(&next));
+    assert!(stacked.is_stacked());
+    let next = LayoutDecision::compute_with_previous(&bp, 1000.0, 1.0, Some(535.0), Some(&stacked));
+    assert!(next.is_stacked());
+}
+
+#[test]
+fn zero_hysteresis_switches_at_threshold() {
+    let bp = Breakpoints {
+        hysteresis: Hysteresis::NONE,
+        ..Breakpoints::default()
+    };
+    let large = LayoutDecision::compute(&bp, 900.0, 1.0, None);
+    let medium = LayoutDecision::compute_with_previous(&bp, 899.0, 1.0, None, Some(&large));
+    assert_eq!(medium.columns, 2);
+}
+
+// Dragging the window back and forth across 900 px must not oscillate the rendered layout.
+// Style mode keeps zoom at 1.0, so harness sizes map 1:1 to physical px.
+#[test]
+fn resize_back_and_forth_does_not_oscillate() {
+    let mut harness = demo_harness(egui::vec2(920.0, 600.0), |app| {
+        app.scaling_mode = ScalingMode::Style;
+    });
+    harness.get_by_label("Columns: 3");
+    for width in [896.0, 904.0, 892.0, 908.0, 898.0] {
+        harness.set_size(egui::vec2(width, 600.0));
+        harness.run();
+        harness.get_by_label("Columns: 3");
+    }
+    assert_eq!(harness.state().layout_transitions, 0);
+}
+
+// A real breakpoint crossing is counted exactly once
+#[test]
+fn crossing_a_breakpoint_counts_one_transition() {
+    let mut harness = demo_harness(egui::vec2(1000.0, 600.0), |app| {
+        app.scaling_mode = ScalingMode::Style;
+    });
+    harness.set_size(egui::vec2(820.0, 600.0));
+    harness.run();
+    harness.get_by_label("Columns: 2");
+    assert_eq!(harness.state().layout_transitions, 1);
+}
+
+// Fluid scale never shrinks as the window grows, stays within its limits and only
+// takes multiples of its step
+#[test]
+fn fluid_scale_is_monotonic_clamped_and_quantized() {
+    let fluid = FluidScale::default();
+    let mut last = 0.0;
+    for width in (200..=2000).step_by(7) {
+        let scale = fluid.scale(width as f32, None);
+        assert!(scale >= last, "scale dropped to {scale} at {width} px");
+        assert!((fluid.min_scale..=fluid.max_scale).contains(&scale));
+        let steps = scale / fluid.step;
+        assert!(
+            (steps - steps.round()).abs() < 1e-3,
+            "{scale} at {width} px"
+        );
+        last = scale;
+    }
+    assert_eq!(fluid.scale(fluid.min_px - 100.0, None), fluid.min_scale);
+    assert_eq!(fluid.scale(fluid.max_px + 100.0, None), fluid.max_scale);
+}
+
+// A width sitting on a rounding boundary keeps the previous step
+#[test]
+fn fluid_scale_holds_previous_step_near_boundary() {
+    let fluid = FluidScale {
+        min_px: 0.0,
+        max_px: 1000.0,
+        min_scale: 1.0,
+        max_scale: 2.0,
+        step: 0.1,
+    };
+    // 1.15 is halfway between the 1.1 and 1.2 steps
+    let low = fluid.scale(145.0, None);
+    assert!((low - 1.1).abs() < 1e-4);
+    assert_eq!(fluid.scale(155.0, Some(low)), low);
+    assert_eq!(fluid.scale(145.0, Some(1.2)), 1.2);
+    // Far enough away, the step follows the width again
+    assert!((fluid.scale(200.0, Some(low)) - 1.2).abs() < 1e-4);
+}
+
+// Resizing in Fluid mode settles within a few frames and the scale grows with width
+#[test]
+fn fluid_mode_settles_and_scale_follows_width() {
+    let mut harness = demo_harness(egui::vec2(360.0, 600.0), |app| {
+        app.scaling_mode = ScalingMode::Fluid;
+    });
+    let scale_pct = |harness: &Harness<'_, DemoApp>| -> i32 {
+        let label = harness.get_by_label_contains("Scale: ").value().unwrap();
+        label["Scale: ".len()..label.len() - 1].parse().unwrap()
+    };
+
+    harness.run();
+    let mut last = scale_pct(&harness);
+    for width in (380..=1400).step_by(60) {
+        harness.set_size(egui::vec2(width as f32, 600.0));
+        // Within the harness's step limit, or this fails
+        harness
+            .try_run()
+            .unwrap_or_else(|err| panic!("{width} px did not settle: {err}"));
+        let scale = scale_pct(&harness);
+        assert!(scale >= last, "scale dropped to {scale}% at {width} pt");
+        last = scale;
+    }
+    assert_eq!(last, 150);
+}
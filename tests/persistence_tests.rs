@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use eframe::App;
+use egui::{ViewportCommand, ViewportEvent, ViewportId};
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+use egui_kittest_demo::{
+    DemoApp, LayoutDirection, Locale, PREFERENCES_KEY, Preferences, ScalingMode, Theme,
+};
+
+// In-memory stand-in for eframe's file-backed storage
+#[derive(Default)]
+struct MemoryStorage(HashMap<String, String>);
+
+impl eframe::Storage for MemoryStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.0.get(key).cloned()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.0.insert(key.to_owned(), value);
+    }
+
+    fn flush(&mut self) {}
+}
+
+#[test]
+fn save_and_restore_round_trip() {
+    let mut app = DemoApp::new();
+    app.name = "Alice".into();
+    app.age = 33;
+    app.counter = -4;
+    app.scaling_mode = ScalingMode::Style;
+    app.left_panel_width_px = Some(312.0);
+    app.set_user_zoom(1.4);
+    app.theme = Theme::HighContrast;
+    app.locale = Locale::De;
+    app.layout_direction = LayoutDirection::RightToLeft;
+
+    let mut storage = MemoryStorage::default();
+    app.save(&mut storage);
+
+    let restored = DemoApp::from_storage(Some(&storage));
+    assert_eq!(restored.name, "Alice");
+    assert_eq!(restored.age, 33);
+    assert_eq!(restored.counter, -4);
+    assert_eq!(restored.scaling_mode, ScalingMode::Style);
+    assert_eq!(restored.left_panel_width_px, Some(312.0));
+    assert_eq!(restored.user_zoom, 1.4);
+    assert_eq!(restored.theme, Theme::HighContrast);
+    assert_eq!(restored.locale, Locale::De);
+    assert_eq!(restored.layout_direction, LayoutDirection::RightToLeft);
+}
+
+#[test]
+fn missing_storage_gives_defaults() {
+    let app = DemoApp::from_storage(None);
+    assert_eq!(app.theme, Theme::default());
+    assert_eq!(app.user_zoom, 1.0);
+
+    let empty = MemoryStorage::default();
+    let app = DemoApp::from_storage(Some(&empty));
+    assert_eq!(app.scaling_mode, ScalingMode::Zoom);
+}
+
+#[test]
+fn corrupt_data_is_ignored() {
+    let mut storage = MemoryStorage::default();
+    storage.0.insert(
+        PREFERENCES_KEY.to_owned(),
+        "(theme: HighContrast, user_zoom: ".into(),
+    );
+    let app = DemoApp::from_storage(Some(&storage));
+    assert_eq!(app.theme, Theme::default());
+    assert_eq!(app.user_zoom, 1.0);
+}
+
+#[test]
+fn newer_version_is_ignored() {
+    let mut storage = MemoryStorage::default();
+    storage.0.insert(
+        PREFERENCES_KEY.to_owned(),
+        "(version: 999, theme: HighContrast)".into(),
+    );
+    assert!(Preferences::load(&storage).is_none());
+    assert_eq!(
+        DemoApp::from_storage(Some(&storage)).theme,
+        Theme::default()
+    );
+}
+
+// Unversioned data with a subset of fields still loads; out-of-range values are clamped
+#[test]
+fn old_partial_data_is_migrated_and_clamped() {
+    let mut storage = MemoryStorage::default();
+    storage.0.insert(
+        PREFERENCES_KEY.to_owned(),
+        "(name: \"Old\", age: 500, theme: Light, user_zoom: 9.0, left_panel_width_px: Some(-3.0))"
+            .into(),
+    );
+    let app = DemoApp::from_storage(Some(&storage));
+    assert_eq!(app.name, "Old");
+    assert_eq!(app.age, 120);
+    assert_eq!(app.theme, Theme::Light);
+    assert_eq!(app.user_zoom, 3.0);
+    assert_eq!(app.left_panel_width_px, None);
+}
+
+// Content changed through the UI survives a save/restore and renders again after restart
+#[test]
+fn ui_content_survives_restart() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("Counter: 2");
+
+    let mut storage = MemoryStorage::default();
+    harness.state_mut().save(&mut storage);
+
+    let harness = demo_harness(Viewport::Medium, |app| {
+        *app = DemoApp::from_storage(Some(&storage));
+    });
+    harness.get_by_label("Counter: 2");
+    // Restored edits were never saved to a document, so they are still unsaved
+    assert!(harness.state().is_dirty());
+}
+
+// A setting changed through the UI survives a save/restore and renders again after restart
+#[test]
+fn ui_state_survives_restart() {
+    // At zoom 1.0, so clicks land where the menu is drawn
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.get_by_label("View").click();
+    harness.run();
+    harness.get_by_label("Style-based").click();
+    harness.run();
+    harness.get_by_label("Scaling mode: Style");
+
+    let mut storage = MemoryStorage::default();
+    harness.state_mut().save(&mut storage);
+
+    let harness = demo_harness(Viewport::Medium, |app| {
+        *app = DemoApp::from_storage(Some(&storage));
+    });
+    harness.get_by_label("Scaling mode: Style");
+}
+
+// eframe still calls `save` after the user discards changes on close; the discarded
+// document content must not come back on the next launch
+#[test]
+fn discarded_changes_are_not_restored() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.state_mut().name = "Draft".into();
+    harness
+        .input_mut()
+        .viewports
+        .entry(ViewportId::ROOT)
+        .or_default()
+        .events
+        .push(ViewportEvent::Close);
+    harness.run();
+    harness.get_by_label("Discard").click();
+    harness.step();
+    let closed = &harness.output().viewport_output[&ViewportId::ROOT].commands;
+    assert!(closed.contains(&ViewportCommand::Close));
+
+    let mut storage = MemoryStorage::default();
+    harness.state_mut().save(&mut storage);
+
+    let restored = DemoApp::from_storage(Some(&storage));
+    assert_eq!(restored.counter, 0);
+    assert!(restored.name.is_empty());
+    assert!(!restored.is_dirty());
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use egui_kittest_demo::testing::demo_harness;
+use egui_kittest_demo::{ScalingMode, StyleScaler, scale_style};
+
+// Every size-like spacing field is scaled, not just the common few
+#[test]
+fn scale_style_covers_all_spacing() {
+    let base = egui::Style::default();
+    let scaled = scale_style(&base, 2.0);
+    let (b, s) = (&base.spacing, &scaled.spacing);
+    assert_eq!(s.item_spacing, b.item_spacing * 2.0);
+    assert_eq!(s.window_margin, b.window_margin * 2.0);
+    assert_eq!(s.menu_margin, b.menu_margin * 2.0);
+    assert_eq!(s.slider_width, b.slider_width * 2.0);
+    assert_eq!(s.combo_width, b.combo_width * 2.0);
+    assert_eq!(s.icon_width, b.icon_width * 2.0);
+    assert_eq!(s.icon_spacing, b.icon_spacing * 2.0);
+    assert_eq!(s.scroll.bar_width, b.scroll.bar_width * 2.0);
+    assert_eq!(s.scroll.floating_width, b.scroll.floating_width * 2.0);
+    // Opacities are not sizes
+    assert_eq!(
+        s.scroll.dormant_handle_opacity,
+        b.scroll.dormant_handle_opacity
+    );
+    for (text_style, font_id) in &scaled.text_styles {
+        assert_eq!(font_id.size, base.text_styles[text_style].size * 2.0);
+    }
+}
+
+#[test]
+fn scaled_styles_are_cached_per_scale() {
+    let base = egui::Style::default();
+    let mut scaler = StyleScaler::default();
+    scaler.set_base(base.clone());
+
+    let first = scaler.scaled(1.15);
+    assert!(Arc::ptr_eq(&first, &scaler.scaled(1.15)));
+    scaler.scaled(1.35);
+    assert_eq!(scaler.cached(), 2);
+    // The base itself needs no copy
+    scaler.scaled(1.0);
+    assert_eq!(scaler.cached(), 2);
+
+    // Setting an identical base keeps the cache
+    scaler.set_base(base);
+    assert_eq!(scaler.cached(), 2);
+}
+
+#[test]
+fn changing_the_base_invalidates_the_cache() {
+    let mut scaler = StyleScaler::default();
+    scaler.set_base(egui::Style::default());
+    let dark = scaler.scaled(1.5);
+
+    let mut light = egui::Style::default();
+    light.visuals = egui::Visuals::light();
+    scaler.set_base(light);
+    assert_eq!(scaler.cached(), 0);
+
+    let rescaled = scaler.scaled(1.5);
+    assert!(!Arc::ptr_eq(&dark, &rescaled));
+    assert!(!rescaled.visuals.dark_mode);
+    assert_eq!(rescaled.spacing.item_spacing, dark.spacing.item_spacing);
+}
+
+// A theme switch made on the context is picked up without scaling the sizes twice
+#[test]
+fn theme_switch_rebases_the_scaled_style() {
+    let base = egui::Style::default();
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.scaling_mode = ScalingMode::Style;
+    });
+    harness.run();
+    // 820 px is Medium, which Style mode scales by 1.15
+    let medium = base.spacing.item_spacing * 1.15;
+    assert_eq!(harness.ctx.style().spacing.item_spacing, medium);
+
+    harness.ctx.set_visuals(egui::Visuals::light());
+    harness.run();
+    let style = harness.ctx.style();
+    assert!(!style.visuals.dark_mode);
+    assert_eq!(style.spacing.item_spacing, medium);
+}
+
+// Leaving Style mode puts the unscaled style back
+#[test]
+fn zoom_mode_restores_the_base_style() {
+    let base = egui::Style::default();
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.scaling_mode = ScalingMode::Style;
+    });
+    harness.run();
+    assert_ne!(harness.ctx.style().spacing, base.spacing);
+    harness.state_mut().scaling_mode = ScalingMode::Zoom;
+    harness.run();
+    assert_eq!(harness.ctx.style().spacing, base.spacing);
+}
@@ -0,0 +1,110 @@
+use egui::{Key, KeyboardShortcut, Modifiers};
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{Viewport, demo_harness, press};
+use egui_kittest_demo::{ScalingMode, ShortcutAction, ShortcutConflict, ShortcutRegistry};
+
+#[test]
+fn conflicts_are_rejected_at_registration() {
+    let mut registry = ShortcutRegistry::default();
+    let show_dialog = KeyboardShortcut::new(Modifiers::COMMAND, Key::D);
+    registry
+        .register(show_dialog, ShortcutAction::ShowDialog)
+        .unwrap();
+
+    let err = registry
+        .register(show_dialog, ShortcutAction::Increment)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ShortcutConflict {
+            shortcut: show_dialog,
+            existing: ShortcutAction::ShowDialog,
+            rejected: ShortcutAction::Increment,
+        }
+    );
+    assert!(
+        err.to_string().contains("already bound to ShowDialog"),
+        "{err}"
+    );
+
+    // Ctrl and the platform-neutral Command modifier collide too
+    let ctrl_d = KeyboardShortcut::new(Modifiers::CTRL, Key::D);
+    assert!(
+        registry
+            .register(ctrl_d, ShortcutAction::Decrement)
+            .is_err()
+    );
+
+    // Adding Shift makes it a different combo
+    let shift = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::D);
+    registry.register(shift, ShortcutAction::Decrement).unwrap();
+    assert_eq!(registry.bindings().len(), 2);
+}
+
+#[test]
+fn standard_registry_binds_every_action() {
+    let registry = ShortcutRegistry::standard();
+    for action in [
+        ShortcutAction::Undo,
+        ShortcutAction::Redo,
+        ShortcutAction::Increment,
+        ShortcutAction::Decrement,
+        ShortcutAction::ShowDialog,
+        ShortcutAction::ToggleScaling,
+        ShortcutAction::FocusSearch,
+        ShortcutAction::ZoomIn,
+        ShortcutAction::ZoomOut,
+        ShortcutAction::ResetZoom,
+    ] {
+        assert!(
+            registry.shortcut_for(action).is_some(),
+            "{action:?} unbound"
+        );
+    }
+}
+
+#[test]
+fn shortcuts_trigger_their_actions() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    press(&mut harness, Modifiers::COMMAND, Key::ArrowUp);
+    press(&mut harness, Modifiers::COMMAND, Key::ArrowUp);
+    press(&mut harness, Modifiers::COMMAND, Key::ArrowDown);
+    harness.get_by_label("Counter: 1");
+
+    press(&mut harness, Modifiers::COMMAND | Modifiers::SHIFT, Key::M);
+    harness.get_by_label("Scaling mode: Style");
+
+    press(&mut harness, Modifiers::COMMAND, Key::F);
+    assert!(harness.get_by_label("Search cards").is_focused());
+
+    press(&mut harness, Modifiers::COMMAND, Key::D);
+    harness.get_by_label("Confirmation");
+    // The open modal blocks shortcuts for the page behind it
+    press(&mut harness, Modifiers::COMMAND, Key::ArrowUp);
+    harness.get_by_label("Counter: 1");
+    assert_eq!(harness.state().counter, 1);
+    assert_eq!(harness.state().scaling_mode, ScalingMode::Style);
+}
+
+#[test]
+fn help_window_lists_every_binding() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    let bindings = harness.state().shortcuts.bindings().to_vec();
+    harness.get_by_label("Help").click();
+    harness.run();
+    harness.get_by_label("Keyboard Shortcuts").click();
+    harness.run();
+
+    harness.get_by_label("Keyboard Shortcuts");
+    for (_, action) in &bindings {
+        harness.get_by_label(action.description());
+        // Key labels follow the platform's conventions (Ctrl on Linux, ⌘ on macOS),
+        // and actions with several combos share one row
+        let combos: Vec<String> = bindings
+            .iter()
+            .filter(|(_, bound)| bound == action)
+            .map(|(shortcut, _)| harness.ctx.format_shortcut(shortcut))
+            .collect();
+        harness.get_by_label(&combos.join(" or "));
+    }
+}
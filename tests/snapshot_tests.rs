@@ -1,5 +1,6 @@
-use eframe::App; // bring trait for DemoApp::update into scope
 use egui_kittest::{Harness, kittest::Queryable};
+use egui_kittest_demo::Theme;
+use egui_kittest_demo::testing::demo_harness;
 
 // Verify a simple UI renders and snapshot is saved at a small size
 #[test]
@@ -24,13 +25,7 @@ fn snapshot_small_layout() {
 // Verify our DemoApp adapts across sizes and create snapshots for each size
 #[test]
 fn demo_app_responsive_snapshots() {
-    let mut app = egui_kittest_demo::DemoApp::default();
-    let mut harness = Harness::builder()
-        .with_size(egui::vec2(360.0, 280.0))
-        .build(|ctx| {
-            let mut frame = eframe::Frame::_new_kittest();
-            app.update(ctx, &mut frame);
-        });
+    let mut harness = demo_harness(egui::vec2(360.0, 280.0), |_| {});
 
     // Narrow view
     harness.get_by_label("egui_kittest Demo App");
@@ -47,6 +42,18 @@ fn demo_app_responsive_snapshots() {
     harness.snapshot("demo_wide");
 }
 
+// One snapshot per theme at the medium size
+#[test]
+fn demo_app_theme_snapshots() {
+    for theme in [Theme::Light, Theme::Dark, Theme::HighContrast] {
+        let mut harness = demo_harness(egui::vec2(600.0, 500.0), |app| app.theme = theme);
+        harness.run();
+        harness.get_by_label(&format!("Theme: {}", theme.label()));
+        let name = theme.label().to_lowercase().replace(' ', "_");
+        harness.snapshot(format!("demo_theme_{name}"));
+    }
+}
+
 // Verify fit_contents works on simple UI and then snapshot
 #[test]
 fn fit_contents_and_snapshot() {
@@ -0,0 +1,50 @@
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+use egui_kittest_demo::{LayoutMode, ScalingMode};
+
+#[test]
+fn viewport_presets_match_the_documented_layouts() {
+    for (viewport, mode, columns) in [
+        (Viewport::Narrow, LayoutMode::Stacked, 1),
+        (Viewport::Medium, LayoutMode::SideCentral, 2),
+        (Viewport::Wide, LayoutMode::SideCentral, 3),
+    ] {
+        let harness = demo_harness(viewport, |_| {});
+        harness.get_by_label(&format!("Layout: {}", mode.label()));
+        harness.get_by_label(&format!("Columns: {columns}"));
+        let layout = harness.state().layout.expect("a frame has run");
+        assert_eq!(layout.mode, mode, "{viewport:?}");
+        assert_eq!(layout.columns, columns, "{viewport:?}");
+    }
+}
+
+#[test]
+fn state_is_read_and_changed_between_frames() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 2;
+        app.scaling_mode = ScalingMode::Style;
+    });
+    harness.get_by_label("Counter: 2");
+    harness.get_by_label("Scaling mode: Style");
+
+    harness.get_by_label("Increment").click();
+    harness.run();
+    assert_eq!(harness.state().counter, 3);
+
+    harness.state_mut().name = "Ada".into();
+    harness.run();
+    harness.get_by_label_contains("Hello, Ada!");
+}
+
+#[test]
+fn custom_sizes_are_accepted() {
+    let harness = demo_harness(egui::vec2(500.0, 400.0), |_| {});
+    harness.get_by_label("Layout: Stacked");
+    harness.get_by_label("Columns: 1");
+    assert!(
+        harness
+            .state()
+            .layout
+            .is_some_and(|layout| layout.is_stacked())
+    );
+}
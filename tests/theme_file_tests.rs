@@ -0,0 +1,126 @@
+use std::path::Path;
+use std::time::Duration;
+
+use egui::Color32;
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{TempFile, demo_harness, fixture};
+use egui_kittest_demo::{HexColor, ScalingMode, ThemeError, ThemeFile};
+
+// Rewrite a watched file with a modification time clearly after the previous one, so
+// the change is seen even on filesystems with coarse timestamps
+fn rewrite(path: &Path, text: &str) {
+    let previous = std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok();
+    std::fs::write(path, text).unwrap();
+    if let Some(previous) = previous {
+        let file = std::fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(previous + Duration::from_secs(2))
+            .unwrap();
+    }
+}
+
+fn color(hex: &str) -> Color32 {
+    Color32::from_hex(hex).unwrap()
+}
+
+#[test]
+fn fixture_theme_applies_on_top_of_the_style() {
+    let theme = ThemeFile::load(&fixture("themes/brand.ron")).unwrap();
+    assert_eq!(theme.name, "Brand");
+    assert_eq!(theme.panel_fill, Some(HexColor(color("#1b2a41"))));
+
+    let mut style = egui::Style::default();
+    theme.apply(&mut style);
+    let visuals = &style.visuals;
+    assert_eq!(visuals.override_text_color, Some(color("#f5f0e6")));
+    assert_eq!(visuals.panel_fill, color("#1b2a41"));
+    assert_eq!(visuals.selection.stroke.color, color("#ffd166"));
+    assert_eq!(visuals.widgets.hovered.bg_fill, color("#35507a"));
+    assert_eq!(visuals.widgets.active.fg_stroke.color, color("#1b2a41"));
+    assert_eq!(visuals.widgets.inactive.bg_stroke.width, 1.5);
+    assert_eq!(visuals.window_corner_radius, 6.into());
+    assert_eq!(style.text_styles[&egui::TextStyle::Heading].size, 24.0);
+    // Unset fields keep the built-in value
+    let default = egui::Style::default();
+    assert_eq!(
+        style.text_styles[&egui::TextStyle::Small],
+        default.text_styles[&egui::TextStyle::Small]
+    );
+    assert_eq!(
+        visuals.widgets.open.bg_fill,
+        default.visuals.widgets.open.bg_fill
+    );
+}
+
+#[test]
+fn parse_errors_name_the_problem_and_position() {
+    let err = ThemeFile::load(&fixture("themes/bad_color.ron")).unwrap_err();
+    let message = err.to_string();
+    assert!(matches!(err, ThemeError::Format(_)));
+    assert!(message.starts_with("invalid theme file: 3:"), "{message}");
+    assert!(message.contains("found \"navy\""), "{message}");
+
+    let message = ThemeFile::load(&fixture("themes/unknown_field.ron"))
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("panel_fil"), "{message}");
+
+    let message = ThemeFile::parse("(font_sizes: (body: -2.0))")
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        message,
+        "invalid theme file: font_sizes.body must be positive, found -2"
+    );
+
+    let err = ThemeFile::load(&fixture("themes/missing.ron")).unwrap_err();
+    assert!(matches!(err, ThemeError::Io(_)));
+}
+
+// Font sizes from the file are the base that Style mode scales
+#[test]
+fn custom_theme_composes_with_style_scaling() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.scaling_mode = ScalingMode::Style;
+        app.load_theme_file(fixture("themes/brand.ron")).unwrap();
+    });
+    harness.run();
+    harness.get_by_label("Custom theme: Brand");
+    let style = harness.ctx.style();
+    assert_eq!(style.visuals.panel_fill, color("#1b2a41"));
+    // 820 px is Medium, which Style mode scales by 1.15
+    assert_eq!(
+        style.text_styles[&egui::TextStyle::Heading].size,
+        24.0 * 1.15
+    );
+}
+
+#[test]
+fn theme_file_is_reloaded_when_it_changes() {
+    let theme = TempFile::new("hot_reload");
+    let path = &theme.path;
+    rewrite(path, r##"(name: "First", panel_fill: "#102030")"##);
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.load_theme_file(path).unwrap();
+    });
+    harness.run();
+    harness.get_by_label("Custom theme: First");
+
+    rewrite(path, r##"(name: "Second", panel_fill: "#405060")"##);
+    // Each step advances the clock past the poll interval
+    harness.run_steps(3);
+    harness.get_by_label("Custom theme: Second");
+    assert_eq!(harness.ctx.style().visuals.panel_fill, color("#405060"));
+
+    // A broken edit is reported and the last good theme stays
+    rewrite(path, r##"(name: "Third", panel_fill: 12)"##);
+    harness.run_steps(3);
+    harness.get_by_label("Custom theme: Second");
+    harness.get_by_label_contains("invalid theme file");
+    assert!(harness.state().theme_error.is_some());
+
+    harness.state_mut().clear_custom_theme();
+    assert!(harness.state().custom_theme.is_none());
+    assert!(harness.state().theme_error.is_none());
+}
@@ -0,0 +1,53 @@
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::demo_harness;
+use egui_kittest_demo::{ScalingMode, Theme};
+
+#[test]
+fn each_theme_sets_its_visuals() {
+    for theme in Theme::ALL {
+        let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+            app.theme = theme;
+        });
+        harness.run();
+        harness.get_by_label(&format!("Theme: {}", theme.label()));
+
+        let visuals = harness.ctx.style().visuals.clone();
+        // The harness reports no system theme, so System falls back to dark
+        assert_eq!(visuals.dark_mode, theme != Theme::Light, "{theme:?}");
+        assert_eq!(
+            visuals.override_text_color == Some(egui::Color32::WHITE),
+            theme == Theme::HighContrast,
+            "{theme:?}"
+        );
+    }
+}
+
+// Picking a theme from the View menu keeps Style-mode scaling, and can be undone
+#[test]
+fn theme_switch_composes_with_style_scaling() {
+    let base = egui::Style::default();
+    let scaled_spacing = base.spacing.item_spacing * 1.15;
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.scaling_mode = ScalingMode::Style;
+        app.theme = Theme::Light;
+    });
+    harness.run();
+    assert_eq!(harness.ctx.style().spacing.item_spacing, scaled_spacing);
+    assert!(!harness.ctx.style().visuals.dark_mode);
+
+    harness.get_by_label("View").click();
+    harness.run();
+    harness.get_by_label("High Contrast").click();
+    harness.run();
+    harness.get_by_label("Theme: High Contrast");
+    let style = harness.ctx.style();
+    assert_eq!(style.spacing.item_spacing, scaled_spacing);
+    assert!(style.visuals.dark_mode);
+    assert_eq!(
+        style.visuals.override_text_color,
+        Some(egui::Color32::WHITE)
+    );
+    assert_eq!(harness.state().theme, Theme::HighContrast);
+    assert!(harness.state_mut().undo());
+    assert_eq!(harness.state().theme, Theme::Light);
+}
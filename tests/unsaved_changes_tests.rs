@@ -0,0 +1,153 @@
+use egui::{ViewportCommand, ViewportEvent, ViewportId};
+use egui_kittest::{Harness, kittest::Queryable};
+use egui_kittest_demo::testing::{TempFile, Viewport, click_menu_item, demo_harness};
+use egui_kittest_demo::{DemoApp, Document, FixedPathPicker, PendingAction};
+
+// Simulate the user clicking the window's close button
+fn request_close(harness: &mut Harness<'_, DemoApp>) {
+    harness
+        .input_mut()
+        .viewports
+        .entry(ViewportId::ROOT)
+        .or_default()
+        .events
+        .push(ViewportEvent::Close);
+    harness.step();
+}
+
+fn sent_command(harness: &Harness<'_, DemoApp>, command: &ViewportCommand) -> bool {
+    harness
+        .output()
+        .viewport_output
+        .get(&ViewportId::ROOT)
+        .is_some_and(|output| output.commands.contains(command))
+}
+
+#[test]
+fn new_on_clean_document_skips_the_prompt() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    click_menu_item(&mut harness, "File", "New");
+    assert!(harness.query_by_label("Save changes?").is_none());
+}
+
+#[test]
+fn new_with_changes_cancel_keeps_state() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 4;
+    });
+    click_menu_item(&mut harness, "File", "New");
+    harness.get_by_label("Save changes?");
+    harness.get_by_label("Do you want to save changes to Untitled?");
+
+    harness.get_by_label("Cancel").click();
+    harness.run();
+    assert!(harness.query_by_label("Save changes?").is_none());
+    harness.get_by_label("Counter: 4");
+    assert_eq!(harness.state().pending_action, None);
+    assert!(harness.state().is_dirty());
+}
+
+#[test]
+fn new_with_changes_discard_resets() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 4;
+    });
+    click_menu_item(&mut harness, "File", "New");
+    harness.get_by_label("Discard").click();
+    harness.run();
+    harness.get_by_label("Counter: 0");
+    assert!(!harness.state().is_dirty());
+}
+
+#[test]
+fn new_with_changes_save_writes_then_resets() {
+    let document = TempFile::new("guard_save");
+    let path = &document.path;
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.file_picker = Box::new(FixedPathPicker::new(path));
+        app.name = "Ada".into();
+        app.age = 36;
+        app.counter = 9;
+    });
+    click_menu_item(&mut harness, "File", "New");
+    harness.get_by_label("Save").click();
+    harness.run();
+    harness.get_by_label("Counter: 0");
+    assert_eq!(Document::read(path).unwrap().counter, 9);
+}
+
+// The prompt is modal: clicks on the app behind it don't reach their widgets
+#[test]
+fn prompt_blocks_clicks_behind_it() {
+    // At zoom 1.0, so clicks land where the widgets are drawn
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |app| {
+        app.counter = 4;
+    });
+    click_menu_item(&mut harness, "File", "New");
+    harness.get_by_label("Save changes?");
+
+    harness.get_by_label("Increment").click();
+    harness.run();
+    harness.get_by_label("File").click();
+    harness.run();
+    harness.get_by_label("Save changes?");
+    assert!(harness.query_by_label("Open").is_none());
+    assert_eq!(harness.state().counter, 4);
+    assert_eq!(harness.state().pending_action, Some(PendingAction::New));
+}
+
+#[test]
+fn escape_cancels_the_prompt() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 2;
+    });
+    click_menu_item(&mut harness, "File", "New");
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert!(harness.query_by_label("Save changes?").is_none());
+    harness.get_by_label("Counter: 2");
+    assert_eq!(harness.state().pending_action, None);
+}
+
+#[test]
+fn close_with_changes_is_intercepted_and_can_be_cancelled() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 1;
+    });
+    request_close(&mut harness);
+    assert!(sent_command(&harness, &ViewportCommand::CancelClose));
+    harness.run();
+    harness.get_by_label("Save changes?");
+
+    harness.get_by_label("Cancel").click();
+    harness.step();
+    assert!(!sent_command(&harness, &ViewportCommand::Close));
+    harness.run();
+    assert!(harness.query_by_label("Save changes?").is_none());
+    harness.get_by_label("Counter: 1");
+    assert_eq!(harness.state().pending_action, None);
+}
+
+#[test]
+fn close_with_changes_discard_closes() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 1;
+    });
+    request_close(&mut harness);
+    harness.run();
+    harness.get_by_label("Discard").click();
+    harness.step();
+    assert!(sent_command(&harness, &ViewportCommand::Close));
+
+    // The follow-up close request from the platform is not intercepted again
+    request_close(&mut harness);
+    assert!(!sent_command(&harness, &ViewportCommand::CancelClose));
+}
+
+#[test]
+fn close_on_clean_document_is_not_intercepted() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    request_close(&mut harness);
+    assert!(!sent_command(&harness, &ViewportCommand::CancelClose));
+    assert_eq!(harness.state().pending_action, None::<PendingAction>);
+}
@@ -0,0 +1,112 @@
+use egui::accesskit::Role;
+use egui_kittest::kittest::{NodeT, Queryable};
+use egui_kittest_demo::testing::{Viewport, demo_harness};
+use egui_kittest_demo::{AGE_RANGE, FieldRules, FieldValue, FormValidator, Rule};
+
+#[test]
+fn rules_report_the_first_failure() {
+    let name = FormValidator::default().name;
+    assert_eq!(
+        name.validate(FieldValue::Text("   ")).as_deref(),
+        Some("Name is required")
+    );
+    assert_eq!(
+        name.validate(FieldValue::Text(&"a".repeat(41))).as_deref(),
+        Some("Name must be at most 40 characters")
+    );
+    assert_eq!(
+        name.validate(FieldValue::Text("R2-D2")).as_deref(),
+        Some("Name cannot contain '2'")
+    );
+    assert_eq!(name.validate(FieldValue::Text("Zoë O'Neil-Smith")), None);
+
+    let age = FieldRules::new("Age", [Rule::Range(18..=65)]);
+    assert_eq!(
+        age.validate(FieldValue::Number(17)).as_deref(),
+        Some("Age must be between 18 and 65")
+    );
+    assert_eq!(age.validate(FieldValue::Number(65)), None);
+}
+
+#[test]
+fn form_errors_gate_validity() {
+    let validator = FormValidator::default();
+    let errors = validator.validate("", 121);
+    assert!(!errors.is_valid());
+    assert!(errors.name.is_some() && errors.age.is_some());
+    assert!(validator.validate("Ada", 36).is_valid());
+    // The age field's own range is never an error
+    assert!(validator.validate("Ada", *AGE_RANGE.start()).is_valid());
+    assert!(validator.validate("Ada", *AGE_RANGE.end()).is_valid());
+}
+
+#[test]
+fn inline_errors_are_labels_and_descriptions() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    // A fresh form shows no errors and no greeting until a field is touched
+    assert!(harness.query_by_label("Name is required").is_none());
+    assert!(harness.query_by_label_contains("Hello").is_none());
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    harness.get_by_label("Name is required");
+    let name = harness.get_by_role(Role::TextInput);
+    assert_eq!(
+        name.accesskit_node().description().as_deref(),
+        Some("Name is required")
+    );
+
+    name.focus();
+    name.type_text("Ada!");
+    harness.run();
+    harness.get_by_label("Name cannot contain '!'");
+    assert!(harness.query_by_label("Name is required").is_none());
+
+    harness.key_press(egui::Key::Backspace);
+    harness.run();
+    assert!(harness.query_by_label_contains("Name cannot").is_none());
+    harness.get_by_label_contains("Hello, Ada!");
+    assert_eq!(
+        harness
+            .get_by_role(Role::TextInput)
+            .accesskit_node()
+            .description(),
+        None
+    );
+}
+
+#[test]
+fn save_is_disabled_until_the_form_is_valid() {
+    let mut harness = demo_harness(Viewport::Medium, |_| {});
+    harness.get_by_label("File").click();
+    harness.run();
+    assert!(harness.get_by_label("Save").accesskit_node().is_disabled());
+    harness.state_mut().save_document();
+    assert_eq!(
+        harness.state().file_error.as_deref(),
+        Some("Fix the form errors before saving")
+    );
+    // A refused save reveals the errors of untouched fields
+    harness.run();
+    harness.get_by_label("Name is required");
+
+    // Fixing the form between frames enables Save in the open menu
+    harness.state_mut().name = "Ada".into();
+    harness.state_mut().age = 36;
+    harness.run();
+    assert!(!harness.get_by_label("Save").accesskit_node().is_disabled());
+}
+
+#[test]
+fn unsaved_changes_prompt_disables_save_for_an_invalid_form() {
+    let mut harness = demo_harness(Viewport::Medium, |app| {
+        app.counter = 1;
+    });
+    harness.get_by_label("File").click();
+    harness.run();
+    harness.get_by_label("New").click();
+    harness.run();
+    harness.get_by_label("Save changes?");
+    assert!(harness.get_by_label("Save").accesskit_node().is_disabled());
+}
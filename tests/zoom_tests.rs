@@ -0,0 +1,61 @@
+use egui::{Key, Modifiers};
+use egui_kittest::kittest::Queryable;
+use egui_kittest_demo::testing::{Viewport, demo_harness, press};
+use egui_kittest_demo::{DemoApp, ScalingMode, SizeClass, USER_ZOOM_RANGE};
+
+// Ctrl+Plus/Minus/0 multiply the adaptive scale and show up in the Scale label
+#[test]
+fn shortcuts_change_the_user_zoom() {
+    let mut harness = demo_harness(egui::vec2(820.0, 600.0), |_| {});
+    harness.run();
+    harness.get_by_label("Scale: 100%");
+
+    press(&mut harness, Modifiers::COMMAND, Key::Plus);
+    press(&mut harness, Modifiers::COMMAND, Key::Equals);
+    harness.get_by_label("Scale: 120% (user zoom 120%)");
+
+    press(&mut harness, Modifiers::COMMAND, Key::Minus);
+    harness.get_by_label("Scale: 110% (user zoom 110%)");
+
+    press(&mut harness, Modifiers::COMMAND, Key::Num0);
+    harness.get_by_label("Scale: 100%");
+    assert_eq!(harness.state().user_zoom, 1.0);
+}
+
+// The override multiplies the adaptive value, while breakpoints keep using physical width
+#[test]
+fn user_zoom_composes_with_the_bucket_without_moving_breakpoints() {
+    let mut harness = demo_harness(Viewport::Wide, |app| {
+        app.set_user_zoom(1.2);
+    });
+    harness.run();
+    harness.get_by_label("Scale: 180% (user zoom 120%)");
+    harness.get_by_label("Columns: 3");
+    let layout = harness.state().layout.expect("layout computed");
+    assert_eq!(layout.size_class, SizeClass::ExtraLarge);
+    assert_eq!(layout.columns, 3);
+}
+
+// In Fluid mode the override multiplies the width-interpolated scale instead
+#[test]
+fn user_zoom_composes_with_the_fluid_scale() {
+    let mut harness = demo_harness(Viewport::Narrow, |app| {
+        app.scaling_mode = ScalingMode::Fluid;
+        app.set_user_zoom(1.2);
+    });
+    harness.run();
+    harness.get_by_label("Scale: 102% (user zoom 120%)");
+}
+
+#[test]
+fn user_zoom_is_clamped_and_rounded() {
+    let mut app = DemoApp::new();
+    app.set_user_zoom(10.0);
+    assert_eq!(app.user_zoom, *USER_ZOOM_RANGE.end());
+    app.set_user_zoom(0.0);
+    assert_eq!(app.user_zoom, *USER_ZOOM_RANGE.start());
+    app.set_user_zoom(f32::NAN);
+    assert_eq!(app.user_zoom, 1.0);
+    app.set_user_zoom(1.1 + 0.1 + 0.1);
+    assert_eq!(app.user_zoom, 1.3);
+}